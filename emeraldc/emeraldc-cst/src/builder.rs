@@ -0,0 +1,260 @@
+use emeraldc_lexer::WideToken;
+use emeraldc_span::{SourceFile, Spanned};
+
+use crate::{
+    GreenNodeBuilder, GreenToken, SyntaxKind, SyntaxNode, Trivia, TriviaKind,
+};
+
+/// Builds the concrete syntax tree from the lexer output.
+///
+/// Blocks are opened by keywords that start a body and closed by `end`, and groups by brackets,
+/// so the tree has a shape even where the parser fails.
+///
+/// Trivia on the same line as a token, up to and including the newline, becomes its trailing
+/// trivia. Everything else becomes leading trivia of the next token, or of the final
+/// [`SyntaxKind::Eof`] token.
+pub struct CstBuilder<'s> {
+    file: &'s SourceFile,
+    builder: GreenNodeBuilder,
+    /// Token that still waits for its trailing trivia.
+    pending: Option<PendingToken>,
+    leading_trivia: Vec<Trivia>,
+}
+
+struct PendingToken {
+    token: WideToken,
+    text: String,
+    leading_trivia: Vec<Trivia>,
+    trailing_trivia: Vec<Trivia>,
+    /// Trailing trivia already reached the end of the line.
+    line_ended: bool,
+}
+
+impl<'s> CstBuilder<'s> {
+    pub fn build(
+        file: &'s SourceFile,
+        tokens: &[Spanned<WideToken>],
+    ) -> SyntaxNode {
+        let mut this = Self::new(file);
+        this.builder.start_node(SyntaxKind::Root);
+        for token in tokens {
+            this.push(token);
+        }
+        this.finish()
+    }
+
+    fn new(file: &'s SourceFile) -> Self {
+        Self {
+            file,
+            builder: GreenNodeBuilder::new(),
+            pending: None,
+            leading_trivia: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, token: &Spanned<WideToken>) {
+        let text = self.file.text(&token.span);
+        match TriviaKind::from_token(&token.value) {
+            Some(kind) => self.push_trivia(kind, text),
+            None => {
+                self.flush_pending(Some(&token.value));
+                self.pending = Some(PendingToken {
+                    token: token.value.clone(),
+                    text: text.to_string(),
+                    leading_trivia: std::mem::take(&mut self.leading_trivia),
+                    trailing_trivia: Vec::new(),
+                    line_ended: false,
+                });
+            }
+        }
+    }
+
+    fn push_trivia(&mut self, kind: TriviaKind, text: &str) {
+        let Some(pending) = self.pending.as_mut().filter(|p| !p.line_ended)
+        else {
+            self.leading_trivia.push(Trivia::new(kind, text));
+            return;
+        };
+        let newline_end = match kind {
+            TriviaKind::Whitespace => text.find('\n').map(|i| i + 1),
+            _ => None,
+        };
+        match newline_end {
+            Some(end) => {
+                let (trailing, leading) = text.split_at(end);
+                pending.trailing_trivia.push(Trivia::new(kind, trailing));
+                pending.line_ended = true;
+                if !leading.is_empty() {
+                    self.leading_trivia.push(Trivia::new(kind, leading));
+                }
+            }
+            None => pending.trailing_trivia.push(Trivia::new(kind, text)),
+        }
+    }
+
+    /// Places the pending token into the tree, opening and closing nodes around it.
+    ///
+    /// `next` is the token that follows it, if any.
+    fn flush_pending(&mut self, next: Option<&WideToken>) {
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+        let green = GreenToken::new(
+            SyntaxKind::from(&pending.token),
+            pending.text,
+            pending.leading_trivia,
+            pending.trailing_trivia,
+        );
+        match Delimiter::new(&pending.token, next) {
+            Delimiter::Open(kind) => {
+                self.builder.start_node(kind);
+                self.builder.token(green);
+            }
+            Delimiter::Close(SyntaxKind::Group)
+                if self.builder.current_kind() == Some(SyntaxKind::Group) =>
+            {
+                self.builder.token(green);
+                self.builder.finish_node();
+            }
+            Delimiter::Close(SyntaxKind::Block)
+                if self.builder.is_inside(SyntaxKind::Block) =>
+            {
+                // unclosed groups end together with the enclosing block
+                while self.builder.current_kind() != Some(SyntaxKind::Block) {
+                    self.builder.finish_node();
+                }
+                self.builder.token(green);
+                self.builder.finish_node();
+            }
+            _ => self.builder.token(green),
+        }
+    }
+
+    fn finish(mut self) -> SyntaxNode {
+        self.flush_pending(None);
+        while self.builder.current_kind() != Some(SyntaxKind::Root) {
+            self.builder.finish_node();
+        }
+        let leading_trivia = std::mem::take(&mut self.leading_trivia);
+        let eof =
+            GreenToken::new(SyntaxKind::Eof, "", leading_trivia, Vec::new());
+        self.builder.token(eof);
+        self.builder.finish_node();
+        SyntaxNode::new_root(self.builder.finish(), self.file.start())
+    }
+}
+
+/// Role of a token in the tree structure.
+enum Delimiter {
+    Open(SyntaxKind),
+    Close(SyntaxKind),
+    None,
+}
+
+impl Delimiter {
+    fn new(token: &WideToken, next: Option<&WideToken>) -> Self {
+        match token {
+            // function types, like `function(Int) -> Int`, have no body
+            WideToken::FunctionKeyword
+                if matches!(next, Some(WideToken::OpenRound)) =>
+            {
+                Self::None
+            }
+            WideToken::FunctionKeyword
+            | WideToken::RecordKeyword
+            | WideToken::EnumKeyword
+            | WideToken::IfKeyword
+            | WideToken::MatchKeyword
+            | WideToken::WhileKeyword
+            | WideToken::LoopKeyword
+            | WideToken::ForKeyword => Self::Open(SyntaxKind::Block),
+            WideToken::EndKeyword => Self::Close(SyntaxKind::Block),
+            WideToken::OpenRound | WideToken::OpenSquare => {
+                Self::Open(SyntaxKind::Group)
+            }
+            WideToken::CloseRound | WideToken::CloseSquare => {
+                Self::Close(SyntaxKind::Group)
+            }
+            _ => Self::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emeraldc_lexer::Lexer;
+    use emeraldc_span::SourceMap;
+    use emeraldc_tokenizer::Tokenizer;
+
+    use super::*;
+    use crate::SyntaxElement;
+
+    fn build(source: &str) -> SyntaxNode {
+        let mut source_map = SourceMap::new();
+        let file = source_map.add_file("test.ed", source);
+        let file = source_map.file(file);
+        let tokens = Lexer::lex(file, Tokenizer::tokenize(file.source()))
+            .collect::<Vec<_>>();
+        CstBuilder::build(file, &tokens)
+    }
+
+    /// Tree written as nested lists of kinds, with token texts.
+    fn shape(node: &SyntaxNode) -> String {
+        let children = node
+            .children()
+            .map(|child| match child {
+                SyntaxElement::Node(node) => shape(&node),
+                SyntaxElement::Token(token) => token.text().to_string(),
+            })
+            .filter(|child| !child.is_empty())
+            .collect::<Vec<_>>();
+        format!("{:?}[{}]", node.kind(), children.join(" "))
+    }
+
+    #[test]
+    fn round_trip() {
+        let sources = [
+            "",
+            "\u{feff}function main()\nend\n",
+            "#!/usr/bin/env emerald\nfunction main()\nend",
+            "function main()\r\n    let a = 1 # one\r\nend\r\n",
+            "#[ outer #[ inner ]# ]#\nfunction f()\nend",
+            "function f()\n#[ unterminated",
+            "let s = \"abc\n  let t = \"\\u{41}\"\n",
+            "a\rb \t\n\n  ) end ( ] é ∑ $",
+            "## docs\nrecord R\n    x: Int\nend\n\n\n",
+        ];
+        for source in sources {
+            let tree = build(source);
+            assert_eq!(tree.to_string(), source);
+            assert_eq!(tree.text_range(), 0..source.len());
+        }
+    }
+
+    #[test]
+    fn blocks_and_groups() {
+        let tree = build("function f(x: Int)\n    g(x[0])\nend\n");
+        assert_eq!(
+            shape(&tree),
+            "Root[Block[function f Group[( x : Int )] \
+             g Group[( x Group[[ 0 ]] )] end]]"
+        );
+    }
+
+    #[test]
+    fn function_types_open_no_block() {
+        let tree =
+            build("function f()\n    let g: function(Int) -> Int = h\nend\n");
+        assert_eq!(
+            shape(&tree),
+            "Root[Block[function f Group[( )] \
+             let g : function Group[( Int )] -> Int = h end]]"
+        );
+    }
+
+    #[test]
+    fn unclosed_groups_end_with_block() {
+        let tree = build("function f(\nend\n)");
+        assert_eq!(shape(&tree), "Root[Block[function f Group[(] end] )]");
+    }
+}
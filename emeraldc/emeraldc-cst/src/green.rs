@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use crate::{SyntaxKind, Trivia};
+
+/// Immutable node that knows nothing about its position and parent, so it can be shared.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GreenNode {
+    kind: SyntaxKind,
+    /// Length of the full text, including trivia of all tokens.
+    text_len: usize,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNode {
+    pub fn new(kind: SyntaxKind, children: Vec<GreenElement>) -> Self {
+        let text_len = children.iter().map(GreenElement::text_len).sum();
+        Self {
+            kind,
+            text_len,
+            children,
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    pub fn text_len(&self) -> usize {
+        self.text_len
+    }
+
+    pub fn children(&self) -> &[GreenElement] {
+        &self.children
+    }
+
+    fn write_text(&self, buffer: &mut String) {
+        for child in &self.children {
+            match child {
+                GreenElement::Node(node) => node.write_text(buffer),
+                GreenElement::Token(token) => token.write_text(buffer),
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for GreenNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut text = String::with_capacity(self.text_len);
+        self.write_text(&mut text);
+        write!(f, "{text}")
+    }
+}
+
+/// Immutable token together with its trivia.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GreenToken {
+    kind: SyntaxKind,
+    text: String,
+    leading_trivia: Vec<Trivia>,
+    /// Trivia on the same line, up to and including the newline.
+    trailing_trivia: Vec<Trivia>,
+}
+
+impl GreenToken {
+    pub fn new(
+        kind: SyntaxKind,
+        text: impl Into<String>,
+        leading_trivia: Vec<Trivia>,
+        trailing_trivia: Vec<Trivia>,
+    ) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            leading_trivia,
+            trailing_trivia,
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    /// Text of the token itself, without trivia.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn leading_trivia(&self) -> &[Trivia] {
+        &self.leading_trivia
+    }
+
+    pub fn trailing_trivia(&self) -> &[Trivia] {
+        &self.trailing_trivia
+    }
+
+    pub fn leading_len(&self) -> usize {
+        self.leading_trivia.iter().map(Trivia::len).sum()
+    }
+
+    /// Length of the full text, including trivia.
+    pub fn text_len(&self) -> usize {
+        let trailing_len: usize =
+            self.trailing_trivia.iter().map(Trivia::len).sum();
+        self.leading_len() + self.text.len() + trailing_len
+    }
+
+    fn write_text(&self, buffer: &mut String) {
+        for trivia in &self.leading_trivia {
+            buffer.push_str(&trivia.text);
+        }
+        buffer.push_str(&self.text);
+        for trivia in &self.trailing_trivia {
+            buffer.push_str(&trivia.text);
+        }
+    }
+}
+
+impl std::fmt::Display for GreenToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut text = String::with_capacity(self.text_len());
+        self.write_text(&mut text);
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GreenElement {
+    Node(Arc<GreenNode>),
+    Token(Arc<GreenToken>),
+}
+
+impl GreenElement {
+    pub fn text_len(&self) -> usize {
+        match self {
+            Self::Node(node) => node.text_len(),
+            Self::Token(token) => token.text_len(),
+        }
+    }
+}
+
+/// Builds green nodes from the top down, like a stack machine.
+#[derive(Debug, Default)]
+pub struct GreenNodeBuilder {
+    /// Kinds of unfinished nodes and the index of their first child.
+    parents: Vec<(SyntaxKind, usize)>,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNodeBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start_node(&mut self, kind: SyntaxKind) {
+        self.parents.push((kind, self.children.len()));
+    }
+
+    pub fn token(&mut self, token: GreenToken) {
+        self.children.push(GreenElement::Token(Arc::new(token)));
+    }
+
+    pub fn finish_node(&mut self) {
+        let (kind, first_child) = self.parents.pop().unwrap();
+        let children = self.children.split_off(first_child);
+        let node = GreenNode::new(kind, children);
+        self.children.push(GreenElement::Node(Arc::new(node)));
+    }
+
+    /// Kind of the innermost unfinished node.
+    pub fn current_kind(&self) -> Option<SyntaxKind> {
+        self.parents.last().map(|(kind, _)| *kind)
+    }
+
+    /// Whether any unfinished node has the given kind.
+    pub fn is_inside(&self, kind: SyntaxKind) -> bool {
+        self.parents.iter().any(|(parent, _)| *parent == kind)
+    }
+
+    /// Returns the single finished root node.
+    pub fn finish(mut self) -> Arc<GreenNode> {
+        assert!(self.parents.is_empty());
+        assert_eq!(self.children.len(), 1);
+        match self.children.pop().unwrap() {
+            GreenElement::Node(node) => node,
+            GreenElement::Token(_) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Lossless concrete syntax tree.
+//!
+//! Unlike the parse tree, it keeps every byte of the input: whitespace and comments are stored as
+//! leading and trailing trivia of tokens, so printing the tree gives back the original text.
+//!
+//! The tree is split in two layers, as in [rowan](https://github.com/rust-analyzer/rowan): the
+//! immutable *green* layer knows only kinds, text and lengths, and the *red* layer adds parents
+//! and absolute offsets on demand.
+
+mod builder;
+mod green;
+mod red;
+mod syntax_kind;
+mod trivia;
+pub use builder::*;
+pub use green::*;
+pub use red::*;
+pub use syntax_kind::*;
+pub use trivia::*;
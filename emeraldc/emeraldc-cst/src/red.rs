@@ -0,0 +1,158 @@
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{GreenElement, GreenNode, GreenToken, SyntaxKind, Trivia};
+
+/// Node with a parent and an absolute offset, created lazily on top of a green node.
+#[derive(Debug, Clone)]
+pub struct SyntaxNode(Rc<NodeData>);
+
+#[derive(Debug)]
+struct NodeData {
+    green: Arc<GreenNode>,
+    parent: Option<SyntaxNode>,
+    offset: usize,
+}
+
+impl SyntaxNode {
+    /// Creates the root of a file that starts at the given offset.
+    pub fn new_root(green: Arc<GreenNode>, offset: usize) -> Self {
+        Self(Rc::new(NodeData {
+            green,
+            parent: None,
+            offset,
+        }))
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.green.kind()
+    }
+
+    pub fn green(&self) -> &Arc<GreenNode> {
+        &self.0.green
+    }
+
+    pub fn parent(&self) -> Option<&SyntaxNode> {
+        self.0.parent.as_ref()
+    }
+
+    /// Byte range of the full text, including trivia.
+    pub fn text_range(&self) -> Range<usize> {
+        self.0.offset..self.0.offset + self.0.green.text_len()
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = SyntaxElement> + '_ {
+        let mut offset = self.0.offset;
+        self.0.green.children().iter().map(move |child| {
+            let child_offset = offset;
+            offset += child.text_len();
+            match child {
+                GreenElement::Node(green) => {
+                    SyntaxElement::Node(Self(Rc::new(NodeData {
+                        green: green.clone(),
+                        parent: Some(self.clone()),
+                        offset: child_offset,
+                    })))
+                }
+                GreenElement::Token(green) => {
+                    SyntaxElement::Token(SyntaxToken {
+                        green: green.clone(),
+                        parent: self.clone(),
+                        offset: child_offset,
+                    })
+                }
+            }
+        })
+    }
+
+    /// All tokens of the subtree, in source order.
+    pub fn tokens(&self) -> Vec<SyntaxToken> {
+        let mut tokens = Vec::new();
+        self.collect_tokens(&mut tokens);
+        tokens
+    }
+
+    fn collect_tokens(&self, tokens: &mut Vec<SyntaxToken>) {
+        for child in self.children() {
+            match child {
+                SyntaxElement::Node(node) => node.collect_tokens(tokens),
+                SyntaxElement::Token(token) => tokens.push(token),
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0.green)
+    }
+}
+
+/// Token with a parent and an absolute offset.
+#[derive(Debug, Clone)]
+pub struct SyntaxToken {
+    green: Arc<GreenToken>,
+    parent: SyntaxNode,
+    /// Offset of the leading trivia.
+    offset: usize,
+}
+
+impl SyntaxToken {
+    pub fn kind(&self) -> SyntaxKind {
+        self.green.kind()
+    }
+
+    pub fn green(&self) -> &Arc<GreenToken> {
+        &self.green
+    }
+
+    pub fn parent(&self) -> &SyntaxNode {
+        &self.parent
+    }
+
+    /// Text of the token itself, without trivia.
+    pub fn text(&self) -> &str {
+        self.green.text()
+    }
+
+    pub fn leading_trivia(&self) -> &[Trivia] {
+        self.green.leading_trivia()
+    }
+
+    pub fn trailing_trivia(&self) -> &[Trivia] {
+        self.green.trailing_trivia()
+    }
+
+    /// Byte range of the token itself, without trivia.
+    pub fn text_range(&self) -> Range<usize> {
+        let start = self.offset + self.green.leading_len();
+        start..start + self.green.text().len()
+    }
+
+    /// Byte range of the full text, including trivia.
+    pub fn full_text_range(&self) -> Range<usize> {
+        self.offset..self.offset + self.green.text_len()
+    }
+}
+
+impl std::fmt::Display for SyntaxToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.green)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+impl SyntaxElement {
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Self::Node(node) => node.kind(),
+            Self::Token(token) => token.kind(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use emeraldc_lexer::WideToken;
+
+/// Kind of a node or a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    /// The whole file.
+    Root,
+    /// Keyword-delimited block, for example, `function ... end`.
+    Block,
+    /// Bracketed group, for example, `( ... )` or `[ ... ]`.
+    Group,
+    Keyword,
+    Identifier,
+    Literal,
+    Punctuation,
+    /// Token the lexer failed to recognize.
+    Error,
+    /// Empty token that holds the trivia at the end of the file.
+    Eof,
+}
+
+impl SyntaxKind {
+    pub fn is_node(&self) -> bool {
+        matches!(self, Self::Root | Self::Block | Self::Group)
+    }
+}
+
+impl From<&WideToken> for SyntaxKind {
+    fn from(token: &WideToken) -> Self {
+        match token {
+            WideToken::FunctionKeyword
+            | WideToken::EndKeyword
+            | WideToken::LetKeyword
+            | WideToken::ReturnKeyword
+            | WideToken::IfKeyword
+            | WideToken::ElifKeyword
+            | WideToken::ElseKeyword
+            | WideToken::WhileKeyword
+            | WideToken::LoopKeyword
+            | WideToken::ForKeyword
+            | WideToken::InKeyword
+            | WideToken::BreakKeyword
+            | WideToken::ContinueKeyword
+            | WideToken::NotKeyword
+            | WideToken::AndKeyword
+            | WideToken::OrKeyword
+            | WideToken::RecordKeyword
+            | WideToken::EnumKeyword
+            | WideToken::MatchKeyword
+            | WideToken::CaseKeyword
+            | WideToken::ImportKeyword
+            | WideToken::ConstKeyword
+            | WideToken::PublicKeyword => Self::Keyword,
+            WideToken::Identifier(_) => Self::Identifier,
+            WideToken::Integer(_)
+            | WideToken::Float(_)
+            | WideToken::String(_) => Self::Literal,
+            WideToken::HadError(_) => Self::Error,
+            _ => Self::Punctuation,
+        }
+    }
+}
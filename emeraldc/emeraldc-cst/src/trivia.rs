@@ -0,0 +1,46 @@
+use emeraldc_lexer::WideToken;
+
+/// Text without meaning for the parser.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub text: String,
+}
+
+impl Trivia {
+    pub fn new(kind: TriviaKind, text: impl Into<String>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    /// Spaces, tabs, newlines and a byte order mark.
+    Whitespace,
+    /// Line and block comments.
+    Comment,
+    /// `##` comments.
+    DocComment,
+}
+
+impl TriviaKind {
+    pub fn from_token(token: &WideToken) -> Option<Self> {
+        match token {
+            WideToken::Invisible => Some(Self::Whitespace),
+            WideToken::Comment => Some(Self::Comment),
+            WideToken::DocComment(_) => Some(Self::DocComment),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::Symbol;
+
+macro_rules! keywords {
+    ($($name:ident: $string:literal,)*) => {
+        #[allow(non_camel_case_types)]
+        enum KeywordIndex {
+            $($name,)*
+        }
+
+        /// Keywords, pre-seeded into the interner.
+        #[allow(non_upper_case_globals)]
+        pub mod kw {
+            use super::*;
+
+            $(pub const $name: Symbol = Symbol::new(KeywordIndex::$name as u32);)*
+        }
+
+        /// Strings of the keywords, in the same order as their symbols.
+        pub(crate) const KEYWORDS: &[&str] = &[$($string,)*];
+    };
+}
+
+keywords! {
+    Function: "function",
+    End: "end",
+    Let: "let",
+    Return: "return",
+    If: "if",
+    Elif: "elif",
+    Else: "else",
+    While: "while",
+    Loop: "loop",
+    For: "for",
+    In: "in",
+    Break: "break",
+    Continue: "continue",
+    Not: "not",
+    And: "and",
+    Or: "or",
+    Record: "record",
+    Enum: "enum",
+    Match: "match",
+    Case: "case",
+    Import: "import",
+    Const: "const",
+    Public: "public",
+}
@@ -0,0 +1,9 @@
+//! Session-wide string interner.
+//!
+//! Every identifier, keyword and literal text is interned once, so later passes can compare and
+//! hash names through cheap [`Symbol`] handles instead of slicing the source.
+
+mod keywords;
+mod symbol;
+pub use keywords::*;
+pub use symbol::*;
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::KEYWORDS;
+
+/// Interned string.
+///
+/// Cheap to copy, compare and hash. The text lives until the end of the compilation session.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    pub(crate) const fn new(index: u32) -> Self {
+        Self(index)
+    }
+
+    pub fn intern(string: &str) -> Self {
+        INTERNER.lock().unwrap().intern(string)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        INTERNER.lock().unwrap().get(*self)
+    }
+}
+
+impl std::fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(Self::intern(&string))
+    }
+}
+
+static INTERNER: LazyLock<Mutex<Interner>> =
+    LazyLock::new(|| Mutex::new(Interner::prefilled(KEYWORDS)));
+
+#[derive(Default)]
+struct Interner {
+    symbols: HashMap<&'static str, Symbol>,
+    strings: Vec<&'static str>,
+}
+
+impl Interner {
+    fn prefilled(strings: &[&str]) -> Self {
+        let mut this = Self::default();
+        for string in strings {
+            this.intern(string);
+        }
+        this
+    }
+
+    fn intern(&mut self, string: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(string) {
+            return *symbol;
+        }
+        // Strings are never freed, so handing out `&'static str` is sound.
+        let string: &'static str = Box::leak(string.into());
+        let symbol = Symbol(self.strings.len() as u32);
+        self.strings.push(string);
+        self.symbols.insert(string, symbol);
+        symbol
+    }
+
+    fn get(&self, symbol: Symbol) -> &'static str {
+        self.strings[symbol.0 as usize]
+    }
+}
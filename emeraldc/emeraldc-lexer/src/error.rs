@@ -1,8 +1,41 @@
+use emeraldc_span::Span;
+
+use crate::Base;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LexerError {
     UnknownCharacter(char),
+    /// Содержит спан открывающей кавычки.
+    UnterminatedString(Span),
+    /// Содержит спан открывающего `#[`.
+    UnterminatedBlockComment(Span),
+    /// Содержит неверную последовательность и её спан внутри строки.
+    InvalidEscape(String, Span),
+    /// Префикс системы счисления без цифр, например, `0x`.
+    EmptyInteger,
+    /// Содержит цифру, недопустимую в системе счисления, и её спан.
+    InvalidDigit(char, Base, Span),
+    /// Экспонента без цифр, например, `1e`.
+    EmptyExponent,
+    /// Содержит неизвестный суффикс числа и его спан.
+    InvalidSuffix(String, Span),
+}
+
+impl LexerError {
+    /// Спан, более точный, чем спан всего токена.
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::UnknownCharacter(_)
+            | Self::EmptyInteger
+            | Self::EmptyExponent => None,
+            Self::UnterminatedString(span)
+            | Self::UnterminatedBlockComment(span)
+            | Self::InvalidEscape(_, span)
+            | Self::InvalidDigit(_, _, span)
+            | Self::InvalidSuffix(_, span) => Some(span),
+        }
+    }
 }
 
 impl std::fmt::Display for LexerError {
@@ -11,6 +44,30 @@ impl std::fmt::Display for LexerError {
             Self::UnknownCharacter(c) => {
                 write!(f, "found an unknown character: \x1b[3m{c:?}\x1b[m")
             }
+            Self::UnterminatedString(_) => {
+                write!(f, "found an unterminated string")
+            }
+            Self::UnterminatedBlockComment(_) => {
+                write!(f, "found an unterminated block comment")
+            }
+            Self::InvalidEscape(escape, _) => {
+                write!(f, "found an invalid escape: \x1b[3m'{escape}'\x1b[m")
+            }
+            Self::EmptyInteger => {
+                write!(f, "found an integer without digits")
+            }
+            Self::InvalidDigit(digit, base, _) => {
+                write!(
+                    f,
+                    "found an invalid digit for a {base} integer: \x1b[3m{digit:?}\x1b[m"
+                )
+            }
+            Self::EmptyExponent => {
+                write!(f, "found an exponent without digits")
+            }
+            Self::InvalidSuffix(suffix, _) => {
+                write!(f, "found an invalid suffix: \x1b[3m'{suffix}'\x1b[m")
+            }
         }
     }
 }
@@ -1,29 +1,34 @@
-use emeraldc_span::{IntoSpanned, Span, Spanned};
+use emeraldc_interner::{Symbol, kw};
+use emeraldc_span::{IntoSpanned, SourceFile, Span, Spanned};
 use emeraldc_tokenizer::{Token, TokenKind};
+use unicode_normalization::{UnicodeNormalization, is_nfc};
 
-use crate::{LexerError, WideToken};
+use crate::{
+    Base, FloatLiteral, FloatSuffix, IntegerLiteral, IntegerSuffix, LexerError,
+    WideToken, unescape,
+};
 
 /// Лексер.
 ///
 /// Дополняет "тонкие" токены, производя полные, то есть "широкие" токены.
 pub struct Lexer<'s> {
-    source: &'s str,
+    file: &'s SourceFile,
     previous_token_end: usize,
 }
 
 impl<'s> Lexer<'s> {
     pub fn lex(
-        source: &'s str,
+        file: &'s SourceFile,
         token_stream: impl Iterator<Item = Token>,
     ) -> impl Iterator<Item = Spanned<WideToken>> {
-        let mut lexer = Self::new(source);
+        let mut lexer = Self::new(file);
         token_stream.map(move |token| lexer.wide_token(token))
     }
 
-    fn new(source: &'s str) -> Self {
+    fn new(file: &'s SourceFile) -> Self {
         Self {
-            source,
-            previous_token_end: 0, // начало файла
+            file,
+            previous_token_end: file.start(), // начало файла
         }
     }
 
@@ -39,29 +44,207 @@ impl<'s> Lexer<'s> {
             TokenKind::IdentifierOrKeyword => {
                 self.identifier_or_keyword_wide_kind(span)
             }
+            TokenKind::Integer {
+                base,
+                empty_digits,
+                suffix_start,
+            } => self.integer_wide_kind(base, empty_digits, suffix_start, span),
+            TokenKind::Float {
+                empty_exponent,
+                suffix_start,
+            } => self.float_wide_kind(empty_exponent, suffix_start, span),
+            TokenKind::String { terminated } => {
+                self.string_wide_kind(terminated, span)
+            }
+            TokenKind::DocComment => self.doc_comment_wide_kind(span),
+            TokenKind::BlockComment { terminated } => {
+                self.block_comment_wide_kind(terminated, span)
+            }
             TokenKind::Unknown(ch) => self.unknown_wide_kind(ch),
             same => self.same_wide_kind(same),
         }
     }
 
     fn identifier_or_keyword_wide_kind(&mut self, span: &Span) -> WideToken {
-        let lexeme: &str = &self.source[span.start..span.end];
-        if let Some(keyword) = self.maybe_keyword(lexeme) {
+        let lexeme = self.file.text(span);
+        let symbol = self.normalized_symbol(lexeme);
+        if let Some(keyword) = self.maybe_keyword(symbol) {
             keyword
         } else {
-            WideToken::Identifier
+            WideToken::Identifier(symbol)
+        }
+    }
+
+    /// Приводит название к NFC, чтобы одинаково выглядящие названия совпадали.
+    fn normalized_symbol(&self, lexeme: &str) -> Symbol {
+        if is_nfc(lexeme) {
+            Symbol::intern(lexeme)
+        } else {
+            Symbol::intern(&lexeme.nfc().collect::<String>())
         }
     }
 
-    fn maybe_keyword(&self, lexeme: &str) -> Option<WideToken> {
-        match lexeme {
-            "function" => Some(WideToken::FunctionKeyword),
-            "end" => Some(WideToken::EndKeyword),
-            "let" => Some(WideToken::LetKeyword),
+    fn maybe_keyword(&self, symbol: Symbol) -> Option<WideToken> {
+        match symbol {
+            kw::Function => Some(WideToken::FunctionKeyword),
+            kw::End => Some(WideToken::EndKeyword),
+            kw::Let => Some(WideToken::LetKeyword),
+            kw::Return => Some(WideToken::ReturnKeyword),
+            kw::If => Some(WideToken::IfKeyword),
+            kw::Elif => Some(WideToken::ElifKeyword),
+            kw::Else => Some(WideToken::ElseKeyword),
+            kw::While => Some(WideToken::WhileKeyword),
+            kw::Loop => Some(WideToken::LoopKeyword),
+            kw::For => Some(WideToken::ForKeyword),
+            kw::In => Some(WideToken::InKeyword),
+            kw::Break => Some(WideToken::BreakKeyword),
+            kw::Continue => Some(WideToken::ContinueKeyword),
+            kw::Not => Some(WideToken::NotKeyword),
+            kw::And => Some(WideToken::AndKeyword),
+            kw::Or => Some(WideToken::OrKeyword),
+            kw::Record => Some(WideToken::RecordKeyword),
+            kw::Enum => Some(WideToken::EnumKeyword),
+            kw::Match => Some(WideToken::MatchKeyword),
+            kw::Case => Some(WideToken::CaseKeyword),
+            kw::Import => Some(WideToken::ImportKeyword),
+            kw::Const => Some(WideToken::ConstKeyword),
+            kw::Public => Some(WideToken::PublicKeyword),
             _ => None,
         }
     }
 
+    fn integer_wide_kind(
+        &mut self,
+        base: Base,
+        empty_digits: bool,
+        suffix_start: usize,
+        span: &Span,
+    ) -> WideToken {
+        if empty_digits {
+            return WideToken::HadError(LexerError::EmptyInteger);
+        }
+        let digits_start = span.start + base.prefix_length();
+        let suffix_start = span.start + suffix_start;
+        if let Some(error) =
+            self.invalid_digit(base, digits_start, suffix_start)
+        {
+            return WideToken::HadError(error);
+        }
+        match self.suffix(suffix_start, span, IntegerSuffix::from_lexeme) {
+            Ok(suffix) => {
+                let digits = self.digits(digits_start, suffix_start);
+                WideToken::Integer(IntegerLiteral {
+                    base,
+                    digits,
+                    suffix,
+                })
+            }
+            Err(error) => WideToken::HadError(error),
+        }
+    }
+
+    /// Ищет цифру, которая больше основания системы счисления.
+    ///
+    /// Токенизатор пропускает все десятичные цифры в двоичных и восьмеричных числах.
+    fn invalid_digit(
+        &self,
+        base: Base,
+        digits_start: usize,
+        digits_end: usize,
+    ) -> Option<LexerError> {
+        let digits = self.file.slice(digits_start, digits_end);
+        let (offset, digit) = digits
+            .char_indices()
+            .find(|(_, c)| *c != '_' && !c.is_digit(base.radix()))?;
+        let start = digits_start + offset;
+        let span = Span::new(start, start + digit.len_utf8());
+        Some(LexerError::InvalidDigit(digit, base, span))
+    }
+
+    fn float_wide_kind(
+        &mut self,
+        empty_exponent: bool,
+        suffix_start: usize,
+        span: &Span,
+    ) -> WideToken {
+        if empty_exponent {
+            return WideToken::HadError(LexerError::EmptyExponent);
+        }
+        let suffix_start = span.start + suffix_start;
+        match self.suffix(suffix_start, span, FloatSuffix::from_lexeme) {
+            Ok(suffix) => {
+                let digits = self.digits(span.start, suffix_start);
+                WideToken::Float(FloatLiteral { digits, suffix })
+            }
+            Err(error) => WideToken::HadError(error),
+        }
+    }
+
+    /// Текст числа без разделителей `_`.
+    fn digits(&self, start: usize, end: usize) -> Symbol {
+        let digits = self.file.slice(start, end).replace('_', "");
+        Symbol::intern(&digits)
+    }
+
+    /// Разбирает необязательный суффикс числа.
+    fn suffix<T>(
+        &self,
+        suffix_start: usize,
+        span: &Span,
+        from_lexeme: impl Fn(&str) -> Option<T>,
+    ) -> Result<Option<T>, LexerError> {
+        if suffix_start == span.end {
+            return Ok(None);
+        }
+        let lexeme = self.file.slice(suffix_start, span.end);
+        match from_lexeme(lexeme) {
+            Some(suffix) => Ok(Some(suffix)),
+            None => {
+                let span = Span::new(suffix_start, span.end);
+                Err(LexerError::InvalidSuffix(lexeme.to_string(), span))
+            }
+        }
+    }
+
+    fn string_wide_kind(&mut self, terminated: bool, span: &Span) -> WideToken {
+        if !terminated {
+            let quote = Span::new(span.start, span.start + 1);
+            return WideToken::HadError(LexerError::UnterminatedString(quote));
+        }
+        let content_start = span.start + 1; // после открывающей кавычки
+        let content = self.file.slice(content_start, span.end - 1);
+        match unescape(content) {
+            Ok(string) => WideToken::String(Symbol::intern(&string)),
+            Err(range) => {
+                let escape = content[range.clone()].to_string();
+                let span = Span::new(
+                    content_start + range.start,
+                    content_start + range.end,
+                );
+                WideToken::HadError(LexerError::InvalidEscape(escape, span))
+            }
+        }
+    }
+
+    fn doc_comment_wide_kind(&mut self, span: &Span) -> WideToken {
+        let text = self.file.slice(span.start + 2, span.end); // после `##`
+        let text = text.strip_prefix(' ').unwrap_or(text);
+        WideToken::DocComment(Symbol::intern(text))
+    }
+
+    fn block_comment_wide_kind(
+        &mut self,
+        terminated: bool,
+        span: &Span,
+    ) -> WideToken {
+        if terminated {
+            WideToken::Comment
+        } else {
+            let opening = Span::new(span.start, span.start + 2);
+            WideToken::HadError(LexerError::UnterminatedBlockComment(opening))
+        }
+    }
+
     fn unknown_wide_kind(&mut self, ch: char) -> WideToken {
         let error = LexerError::UnknownCharacter(ch);
         WideToken::HadError(error)
@@ -70,14 +253,35 @@ impl<'s> Lexer<'s> {
     /// Конвертирует типы, которые одинаковы и в токенизаторе, и в лексере.
     fn same_wide_kind(&self, thin_kind: TokenKind) -> WideToken {
         match thin_kind {
-            TokenKind::Integer => WideToken::Integer,
             TokenKind::OpenRound => WideToken::OpenRound,
             TokenKind::CloseRound => WideToken::CloseRound,
+            TokenKind::OpenSquare => WideToken::OpenSquare,
+            TokenKind::CloseSquare => WideToken::CloseSquare,
             TokenKind::Equal => WideToken::Equal,
+            TokenKind::DoubleEqual => WideToken::DoubleEqual,
+            TokenKind::NotEqual => WideToken::NotEqual,
+            TokenKind::Less => WideToken::Less,
+            TokenKind::LessEqual => WideToken::LessEqual,
+            TokenKind::Greater => WideToken::Greater,
+            TokenKind::GreaterEqual => WideToken::GreaterEqual,
             TokenKind::Plus => WideToken::Plus,
+            TokenKind::PlusEqual => WideToken::PlusEqual,
             TokenKind::Minus => WideToken::Minus,
+            TokenKind::MinusEqual => WideToken::MinusEqual,
+            TokenKind::Arrow => WideToken::Arrow,
             TokenKind::Asterisk => WideToken::Asterisk,
+            TokenKind::AsteriskEqual => WideToken::AsteriskEqual,
+            TokenKind::DoubleAsterisk => WideToken::DoubleAsterisk,
             TokenKind::Slash => WideToken::Slash,
+            TokenKind::SlashEqual => WideToken::SlashEqual,
+            TokenKind::Percent => WideToken::Percent,
+            TokenKind::Tilde => WideToken::Tilde,
+            TokenKind::Dot => WideToken::Dot,
+            TokenKind::DoubleDot => WideToken::DoubleDot,
+            TokenKind::DoubleDotEqual => WideToken::DoubleDotEqual,
+            TokenKind::Comma => WideToken::Comma,
+            TokenKind::Colon => WideToken::Colon,
+            TokenKind::At => WideToken::At,
             TokenKind::Invisible => WideToken::Invisible,
             TokenKind::Comment => WideToken::Comment,
             _ => unreachable!(),
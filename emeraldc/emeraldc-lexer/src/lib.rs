@@ -1,6 +1,10 @@
 mod error;
 mod lexer;
+mod literal;
+mod unescape;
 mod wide_token;
 pub use error::*;
 pub use lexer::*;
+pub use literal::*;
+use unescape::*;
 pub use wide_token::*;
@@ -0,0 +1,114 @@
+use emeraldc_interner::Symbol;
+use serde::{Deserialize, Serialize};
+
+pub use emeraldc_tokenizer::Base;
+
+/// Целочисленный литерал.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegerLiteral {
+    pub base: Base,
+    /// Цифры без префикса, суффикса и разделителей `_`.
+    pub digits: Symbol,
+    pub suffix: Option<IntegerSuffix>,
+}
+
+/// Литерал с плавающей точкой. Всегда десятичный.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FloatLiteral {
+    /// Текст числа без суффикса и разделителей `_`.
+    pub digits: Symbol,
+    pub suffix: Option<FloatSuffix>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegerSuffix {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+}
+
+impl IntegerSuffix {
+    /// Наименьшее значение, которое можно записать с этим суффиксом.
+    pub fn min_value(&self) -> i128 {
+        match self {
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128 => 0,
+            Self::I8 => i8::MIN as i128,
+            Self::I16 => i16::MIN as i128,
+            Self::I32 => i32::MIN as i128,
+            Self::I64 => i64::MIN as i128,
+            Self::I128 => i128::MIN,
+        }
+    }
+
+    /// Наибольшее значение, которое можно записать с этим суффиксом.
+    pub fn max_value(&self) -> u128 {
+        match self {
+            Self::U8 => u8::MAX as u128,
+            Self::U16 => u16::MAX as u128,
+            Self::U32 => u32::MAX as u128,
+            Self::U64 => u64::MAX as u128,
+            Self::U128 => u128::MAX,
+            Self::I8 => i8::MAX as u128,
+            Self::I16 => i16::MAX as u128,
+            Self::I32 => i32::MAX as u128,
+            Self::I64 => i64::MAX as u128,
+            Self::I128 => i128::MAX as u128,
+        }
+    }
+
+    pub fn from_lexeme(lexeme: &str) -> Option<Self> {
+        match lexeme {
+            "u8" => Some(Self::U8),
+            "u16" => Some(Self::U16),
+            "u32" => Some(Self::U32),
+            "u64" => Some(Self::U64),
+            "u128" => Some(Self::U128),
+            "i8" => Some(Self::I8),
+            "i16" => Some(Self::I16),
+            "i32" => Some(Self::I32),
+            "i64" => Some(Self::I64),
+            "i128" => Some(Self::I128),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FloatSuffix {
+    F32,
+    F64,
+}
+
+impl FloatSuffix {
+    pub fn from_lexeme(lexeme: &str) -> Option<Self> {
+        match lexeme {
+            "f32" => Some(Self::F32),
+            "f64" => Some(Self::F64),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for IntegerSuffix {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::I8 => write!(f, "i8"),
+            Self::I16 => write!(f, "i16"),
+            Self::I32 => write!(f, "i32"),
+            Self::I64 => write!(f, "i64"),
+            Self::I128 => write!(f, "i128"),
+        }
+    }
+}
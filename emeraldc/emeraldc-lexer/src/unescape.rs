@@ -0,0 +1,63 @@
+use std::ops::Range;
+use std::str::CharIndices;
+
+/// Раскрывает экранированные последовательности в содержимом строки.
+///
+/// Поддерживаются `\n`, `\t`, `\r`, `\0`, `\\`, `\"` и `\u{...}`.
+///
+/// В случае ошибки возвращает диапазон первой неверной последовательности относительно начала
+/// содержимого.
+pub fn unescape(content: &str) -> Result<String, Range<usize>> {
+    let mut unescaped = String::with_capacity(content.len());
+    let mut chars = content.char_indices();
+    while let Some((start, ch)) = chars.next() {
+        if ch != '\\' {
+            unescaped.push(ch);
+            continue;
+        }
+        match escaped_char(&mut chars) {
+            Some(ch) => unescaped.push(ch),
+            None => {
+                let end = chars.offset();
+                return Err(start..end);
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+/// Читает символ после обратной косой черты.
+fn escaped_char(chars: &mut CharIndices) -> Option<char> {
+    match chars.next()?.1 {
+        'n' => Some('\n'),
+        't' => Some('\t'),
+        'r' => Some('\r'),
+        '0' => Some('\0'),
+        '\\' => Some('\\'),
+        '"' => Some('"'),
+        'u' => unicode_escape(chars),
+        _ => None,
+    }
+}
+
+/// Читает `{...}` после `\u`, содержащий от одной до шести шестнадцатеричных цифр.
+fn unicode_escape(chars: &mut CharIndices) -> Option<char> {
+    if chars.next()?.1 != '{' {
+        return None;
+    }
+    let mut code = 0;
+    let mut digits = 0;
+    loop {
+        match chars.next()?.1 {
+            '}' if digits > 0 => break,
+            ch => {
+                code = code * 16 + ch.to_digit(16)?;
+                digits += 1;
+                if digits > 6 {
+                    return None;
+                }
+            }
+        }
+    }
+    char::from_u32(code)
+}
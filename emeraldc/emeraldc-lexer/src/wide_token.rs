@@ -1,22 +1,71 @@
-use crate::LexerError;
+use emeraldc_interner::Symbol;
+
+use crate::{FloatLiteral, IntegerLiteral, LexerError};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum WideToken {
-    Identifier,
+    Identifier(Symbol),
     FunctionKeyword,
     EndKeyword,
     LetKeyword,
-    Integer,
+    ReturnKeyword,
+    IfKeyword,
+    ElifKeyword,
+    ElseKeyword,
+    WhileKeyword,
+    LoopKeyword,
+    ForKeyword,
+    InKeyword,
+    BreakKeyword,
+    ContinueKeyword,
+    NotKeyword,
+    AndKeyword,
+    OrKeyword,
+    RecordKeyword,
+    EnumKeyword,
+    MatchKeyword,
+    CaseKeyword,
+    ImportKeyword,
+    ConstKeyword,
+    PublicKeyword,
+    Integer(IntegerLiteral),
+    Float(FloatLiteral),
+    /// Содержит строку с раскрытыми экранированными последовательностями.
+    String(Symbol),
     OpenRound,
     CloseRound,
+    OpenSquare,
+    CloseSquare,
     Equal,
+    DoubleEqual,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
     Plus,
+    PlusEqual,
     Minus,
+    MinusEqual,
+    Arrow,
     Asterisk,
+    AsteriskEqual,
+    DoubleAsterisk,
     Slash,
+    SlashEqual,
+    Percent,
+    Tilde,
+    Dot,
+    DoubleDot,
+    DoubleDotEqual,
+    Comma,
+    Colon,
+    At,
     Invisible,
     Comment,
+    /// Содержит текст комментария без `##`.
+    DocComment(Symbol),
     /// Содержит случившуюся ошибку.
     HadError(LexerError),
 }
@@ -37,22 +86,77 @@ impl WideToken {
 impl std::fmt::Display for WideToken {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Self::Identifier => write!(f, "an identifier"),
+            Self::Identifier(_) => write!(f, "an identifier"),
             Self::FunctionKeyword => {
                 write!(f, "\x1b[3m'function'\x1b[m keyword")
             }
             Self::EndKeyword => write!(f, "\x1b[3m'end'\x1b[m keyword"),
             Self::LetKeyword => write!(f, "\x1b[3m'let'\x1b[m keyword"),
-            Self::Integer => write!(f, "an integer"),
+            Self::ReturnKeyword => {
+                write!(f, "\x1b[3m'return'\x1b[m keyword")
+            }
+            Self::IfKeyword => write!(f, "\x1b[3m'if'\x1b[m keyword"),
+            Self::ElifKeyword => write!(f, "\x1b[3m'elif'\x1b[m keyword"),
+            Self::ElseKeyword => write!(f, "\x1b[3m'else'\x1b[m keyword"),
+            Self::WhileKeyword => write!(f, "\x1b[3m'while'\x1b[m keyword"),
+            Self::LoopKeyword => write!(f, "\x1b[3m'loop'\x1b[m keyword"),
+            Self::ForKeyword => write!(f, "\x1b[3m'for'\x1b[m keyword"),
+            Self::InKeyword => write!(f, "\x1b[3m'in'\x1b[m keyword"),
+            Self::BreakKeyword => write!(f, "\x1b[3m'break'\x1b[m keyword"),
+            Self::ContinueKeyword => {
+                write!(f, "\x1b[3m'continue'\x1b[m keyword")
+            }
+            Self::NotKeyword => write!(f, "\x1b[3m'not'\x1b[m keyword"),
+            Self::AndKeyword => write!(f, "\x1b[3m'and'\x1b[m keyword"),
+            Self::OrKeyword => write!(f, "\x1b[3m'or'\x1b[m keyword"),
+            Self::RecordKeyword => {
+                write!(f, "\x1b[3m'record'\x1b[m keyword")
+            }
+            Self::EnumKeyword => write!(f, "\x1b[3m'enum'\x1b[m keyword"),
+            Self::MatchKeyword => write!(f, "\x1b[3m'match'\x1b[m keyword"),
+            Self::CaseKeyword => write!(f, "\x1b[3m'case'\x1b[m keyword"),
+            Self::ImportKeyword => {
+                write!(f, "\x1b[3m'import'\x1b[m keyword")
+            }
+            Self::ConstKeyword => write!(f, "\x1b[3m'const'\x1b[m keyword"),
+            Self::PublicKeyword => {
+                write!(f, "\x1b[3m'public'\x1b[m keyword")
+            }
+            Self::Integer(_) => write!(f, "an integer"),
+            Self::Float(_) => write!(f, "a float"),
+            Self::String(_) => write!(f, "a string"),
             Self::OpenRound => write!(f, "\x1b[3m'('\x1b[m"),
             Self::CloseRound => write!(f, "\x1b[3m')'\x1b[m"),
+            Self::OpenSquare => write!(f, "\x1b[3m'['\x1b[m"),
+            Self::CloseSquare => write!(f, "\x1b[3m']'\x1b[m"),
             Self::Equal => write!(f, "\x1b[3m'='\x1b[m"),
+            Self::DoubleEqual => write!(f, "\x1b[3m'=='\x1b[m"),
+            Self::NotEqual => write!(f, "\x1b[3m'!='\x1b[m"),
+            Self::Less => write!(f, "\x1b[3m'<'\x1b[m"),
+            Self::LessEqual => write!(f, "\x1b[3m'<='\x1b[m"),
+            Self::Greater => write!(f, "\x1b[3m'>'\x1b[m"),
+            Self::GreaterEqual => write!(f, "\x1b[3m'>='\x1b[m"),
             Self::Plus => write!(f, "\x1b[3m'+'\x1b[m"),
+            Self::PlusEqual => write!(f, "\x1b[3m'+='\x1b[m"),
             Self::Minus => write!(f, "\x1b[3m'-'\x1b[m"),
+            Self::MinusEqual => write!(f, "\x1b[3m'-='\x1b[m"),
+            Self::Arrow => write!(f, "\x1b[3m'->'\x1b[m"),
             Self::Asterisk => write!(f, "\x1b[3m'*'\x1b[m"),
+            Self::AsteriskEqual => write!(f, "\x1b[3m'*='\x1b[m"),
+            Self::DoubleAsterisk => write!(f, "\x1b[3m'**'\x1b[m"),
             Self::Slash => write!(f, "\x1b[3m'/'\x1b[m"),
+            Self::SlashEqual => write!(f, "\x1b[3m'/='\x1b[m"),
+            Self::Percent => write!(f, "\x1b[3m'%'\x1b[m"),
+            Self::Tilde => write!(f, "\x1b[3m'~'\x1b[m"),
+            Self::Dot => write!(f, "\x1b[3m'.'\x1b[m"),
+            Self::DoubleDot => write!(f, "\x1b[3m'..'\x1b[m"),
+            Self::DoubleDotEqual => write!(f, "\x1b[3m'..='\x1b[m"),
+            Self::Comma => write!(f, "\x1b[3m','\x1b[m"),
+            Self::Colon => write!(f, "\x1b[3m':'\x1b[m"),
+            Self::At => write!(f, "\x1b[3m'@'\x1b[m"),
             Self::Invisible => write!(f, "an invisible symbol"),
             Self::Comment => write!(f, "a comment"),
+            Self::DocComment(_) => write!(f, "a doc comment"),
             _ => unreachable!(),
         }
     }
@@ -1,9 +1,14 @@
+use std::collections::HashSet;
+
+use emeraldc_interner::Symbol;
 use emeraldc_lexer::WideToken;
-use emeraldc_span::IntoSpanned;
+use emeraldc_span::{IntoSpanned, Span};
 
 use crate::{
-    Declaration, FatalParserError, Function, IntroducerKind, Parsed, Parser,
-    Statement, Subparser, span_from_parsed,
+    Const, Declaration, Enum, FatalParserError, Field, Function, Import,
+    IntroducerKind, NodeError, Parameter, Parsed, Parser, PathSegment, Record,
+    ReturnType, Statement, Subparser, TypeAnnotation, Variant,
+    span_from_parsed,
 };
 
 pub struct DeclarationParser<'p> {
@@ -31,54 +36,383 @@ impl<'p> DeclarationParser<'p> {
         }
     }
 
-    fn invalid_introducer<T>(&mut self) -> Result<T, FatalParserError> {
+    fn invalid_introducer(
+        &mut self,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
         let token = self.parser.tokens.next().unwrap();
+        if token.value.had_error() {
+            let error =
+                Err(NodeError::lexer(token.value.as_error(), token.span));
+            return Ok(error);
+        }
         Err(FatalParserError::InvalidDeclarationIntroducer(token.value))
     }
 
-    fn parse_unchecked(self) -> Result<Parsed<Declaration>, FatalParserError> {
-        match self.parser.tokens.peek().unwrap().value {
-            WideToken::FunctionKeyword => self.parse_function(),
-            _ => Err(FatalParserError::CompilerBug("unreachable variant")),
+    fn parse_unchecked(
+        mut self,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let public = if self.parser.is_next(&WideToken::PublicKeyword) {
+            Some(self.parser.expect(WideToken::PublicKeyword)?)
+        } else {
+            None
+        };
+        match self.parser.tokens.peek().map(|t| &t.value) {
+            Some(WideToken::FunctionKeyword) => self.parse_function(public),
+            Some(WideToken::RecordKeyword) => self.parse_record(public),
+            Some(WideToken::EnumKeyword) => self.parse_enum(public),
+            Some(WideToken::ConstKeyword) => self.parse_const(public),
+            Some(WideToken::ImportKeyword) => self.parse_import(public),
+            // only after `public`
+            Some(_) => self.missing_declaration(),
+            None => {
+                let public = public.expect("only `public` can end the file");
+                let span = span_from_parsed(&public);
+                Ok(Err(NodeError::MissingDeclaration.into_spanned(span)))
+            }
+        }
+    }
+
+    /// Reports the token after `public` without consuming it, as it may start
+    /// the next declaration.
+    fn missing_declaration(
+        &mut self,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let token = self.parser.tokens.peek().unwrap();
+        let error = NodeError::UnexpectedToken(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Err(error))
+    }
+
+    /// Span from `public`, if it is present, or from the introducer.
+    fn start_span(
+        public: &Option<Parsed<WideToken>>,
+        introducer: &Parsed<WideToken>,
+    ) -> Span {
+        match public {
+            Some(public) => span_from_parsed(public),
+            None => span_from_parsed(introducer),
         }
     }
 
     fn parse_function(
         mut self,
+        public: Option<Parsed<WideToken>>,
     ) -> Result<Parsed<Declaration>, FatalParserError> {
         let _introducer = self.parser.expect(WideToken::FunctionKeyword)?;
-        let introducer_span = span_from_parsed(&_introducer);
+        let start_span = Self::start_span(&public, &_introducer);
+        let docs = self.parser.take_docs(&start_span);
         let identifier = self.parser.parse_identifier()?;
         let _open_round = self.parser.expect(WideToken::OpenRound)?;
+        let parameters = self.parse_parameters()?;
         let _close_round = self.parser.expect(WideToken::CloseRound)?;
+        let return_type = self.parse_return_type()?;
         let body = self.parse_function_body()?;
         let _end = self.parser.expect(WideToken::EndKeyword)?;
         let end_span = span_from_parsed(&_end);
         let function = Function {
+            docs,
+            public,
             _introducer,
             identifier,
             _open_round,
+            parameters,
             _close_round,
+            return_type,
             body,
             _end,
         };
-        let span = introducer_span.join(end_span);
+        let span = start_span.join(end_span);
         let parsed = Ok(Declaration::Function(function).into_spanned(span));
         Ok(parsed)
     }
 
+    fn parse_record(
+        mut self,
+        public: Option<Parsed<WideToken>>,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::RecordKeyword)?;
+        let start_span = Self::start_span(&public, &_introducer);
+        let docs = self.parser.take_docs(&start_span);
+        let identifier = self.parser.parse_identifier()?;
+        let fields = self.parse_fields()?;
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = start_span.join(span_from_parsed(&_end));
+        let record = Record {
+            docs,
+            public,
+            _introducer,
+            identifier,
+            fields,
+            _end,
+        };
+        let parsed = Ok(Declaration::Record(record).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_fields(&mut self) -> Result<Vec<Parsed<Field>>, FatalParserError> {
+        let mut fields = Vec::new();
+        while !self.is_declaration_end() {
+            let field = self.parse_field()?;
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+
+    /// Parses `name: Type`.
+    fn parse_field(&mut self) -> Result<Parsed<Field>, FatalParserError> {
+        let identifier = match self.parser.parse_identifier()? {
+            Ok(identifier) => identifier,
+            Err(error) => return Ok(Err(error)),
+        };
+        let mut span = identifier.span.clone();
+        let annotation = if self.parser.is_next(&WideToken::Colon) {
+            let annotation = self.parser.parse_type_annotation()?;
+            span = span.join(span_from_parsed(&annotation));
+            annotation
+        } else {
+            Err(NodeError::MissingFieldType.into_spanned(span.clone()))
+        };
+        let field = Field {
+            identifier: Ok(identifier),
+            annotation,
+        };
+        Ok(Ok(field.into_spanned(span)))
+    }
+
+    fn parse_enum(
+        mut self,
+        public: Option<Parsed<WideToken>>,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::EnumKeyword)?;
+        let start_span = Self::start_span(&public, &_introducer);
+        let docs = self.parser.take_docs(&start_span);
+        let identifier = self.parser.parse_identifier()?;
+        let variants = self.parse_variants()?;
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = start_span.join(span_from_parsed(&_end));
+        let enum_ = Enum {
+            docs,
+            public,
+            _introducer,
+            identifier,
+            variants,
+            _end,
+        };
+        let parsed = Ok(Declaration::Enum(enum_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    /// Parses `const NAME: Type = value`.
+    fn parse_const(
+        self,
+        public: Option<Parsed<WideToken>>,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ConstKeyword)?;
+        let start_span = Self::start_span(&public, &_introducer);
+        let docs = self.parser.take_docs(&start_span);
+        let identifier = self.parser.parse_identifier()?;
+        let annotation = if self.parser.is_next(&WideToken::Colon) {
+            self.parser.parse_type_annotation()?
+        } else {
+            let span = span_from_parsed(&identifier);
+            Err(NodeError::MissingConstantType.into_spanned(span))
+        };
+        let _equal = self.parser.expect(WideToken::Equal)?;
+        let value = self.parser.parse_expression()?;
+        let span = start_span.join(span_from_parsed(&value));
+        let const_ = Const {
+            docs,
+            public,
+            _introducer,
+            identifier,
+            annotation: Box::new(annotation),
+            _equal,
+            value,
+        };
+        let parsed = Ok(Declaration::Const(const_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    /// Parses `import path.to.module`.
+    fn parse_import(
+        self,
+        public: Option<Parsed<WideToken>>,
+    ) -> Result<Parsed<Declaration>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ImportKeyword)?;
+        let start_span = Self::start_span(&public, &_introducer);
+        let public = public.map(|public| {
+            let span = span_from_parsed(&public);
+            Err(NodeError::PublicImport.into_spanned(span))
+        });
+        let identifier = self.parser.parse_identifier()?;
+        let mut span = start_span.join(span_from_parsed(&identifier));
+        let mut segments = vec![PathSegment {
+            _dot: None,
+            identifier,
+        }];
+        while self.parser.is_next(&WideToken::Dot) {
+            let _dot = self.parser.expect(WideToken::Dot)?;
+            let identifier = self.parser.parse_identifier()?;
+            span = span.join(span_from_parsed(&identifier));
+            segments.push(PathSegment {
+                _dot: Some(_dot),
+                identifier,
+            });
+        }
+        let import = Import {
+            public,
+            _introducer,
+            segments,
+        };
+        let parsed = Ok(Declaration::Import(import).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_variants(
+        &mut self,
+    ) -> Result<Vec<Parsed<Variant>>, FatalParserError> {
+        let mut variants = Vec::new();
+        while !self.is_declaration_end() {
+            let variant = self.parse_variant()?;
+            variants.push(variant);
+        }
+        Ok(variants)
+    }
+
+    /// Parses `Name` or `Name(Type, ...)`.
+    fn parse_variant(&mut self) -> Result<Parsed<Variant>, FatalParserError> {
+        let identifier = match self.parser.parse_identifier()? {
+            Ok(identifier) => identifier,
+            Err(error) => return Ok(Err(error)),
+        };
+        let mut span = identifier.span.clone();
+        let payload = if self.parser.is_next(&WideToken::OpenRound) {
+            let payload = self.parser.parse_type()?;
+            span = span.join(span_from_parsed(&payload));
+            Some(payload)
+        } else {
+            None
+        };
+        let variant = Variant {
+            identifier: Ok(identifier),
+            payload,
+        };
+        Ok(Ok(variant.into_spanned(span)))
+    }
+
+    /// Parses parameters up to the closing round bracket.
+    ///
+    /// Stops at the first token that cannot start a parameter, so a missing `)` is reported by
+    /// the caller.
+    fn parse_parameters(
+        &mut self,
+    ) -> Result<Vec<Parsed<Parameter>>, FatalParserError> {
+        let mut parameters = Vec::new();
+        let mut names = HashSet::new();
+        while self.is_parameter_start() {
+            let parameter = self.parse_parameter(&mut names)?;
+            parameters.push(parameter);
+        }
+        Ok(parameters)
+    }
+
+    fn is_parameter_start(&mut self) -> bool {
+        self.parser
+            .tokens
+            .peek()
+            .is_some_and(|t| matches!(t.value, WideToken::Identifier(_)))
+    }
+
+    fn parse_parameter(
+        &mut self,
+        names: &mut HashSet<Symbol>,
+    ) -> Result<Parsed<Parameter>, FatalParserError> {
+        let identifier = self.parser.parse_identifier()?;
+        let identifier_span = span_from_parsed(&identifier);
+        let identifier = identifier.and_then(|n| {
+            if names.insert(n.value.symbol) {
+                Ok(n)
+            } else {
+                Err(NodeError::DuplicateParameter(n.value.symbol)
+                    .into_spanned(n.span))
+            }
+        });
+        let annotation =
+            self.parse_parameter_annotation(identifier_span.clone())?;
+        let _comma = self.parse_parameter_comma()?;
+        let span = match &annotation {
+            Ok(annotation) => identifier_span.join(annotation.span.clone()),
+            Err(_) => identifier_span,
+        };
+        let parameter = Parameter {
+            identifier,
+            annotation,
+            _comma,
+        };
+        Ok(Ok(parameter.into_spanned(span)))
+    }
+
+    fn parse_parameter_annotation(
+        &mut self,
+        identifier_span: Span,
+    ) -> Result<Parsed<TypeAnnotation>, FatalParserError> {
+        if self.parser.is_next(&WideToken::Colon) {
+            self.parser.parse_type_annotation()
+        } else {
+            let error =
+                NodeError::MissingParameterType.into_spanned(identifier_span);
+            Ok(Err(error))
+        }
+    }
+
+    /// Comma is optional only before the closing round bracket.
+    ///
+    /// A missing comma is reported only before another parameter, any other
+    /// token is left for the closing bracket.
+    fn parse_parameter_comma(
+        &mut self,
+    ) -> Result<Option<Parsed<WideToken>>, FatalParserError> {
+        if self.parser.is_next(&WideToken::Comma) {
+            let comma = self.parser.expect(WideToken::Comma)?;
+            return Ok(Some(comma));
+        }
+        if !self.is_parameter_start() {
+            return Ok(None);
+        }
+        // the token is left for the next parameter
+        let token = self.parser.tokens.peek().unwrap();
+        let error = NodeError::MissingComma(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Some(Err(error)))
+    }
+
+    fn parse_return_type(
+        &mut self,
+    ) -> Result<Option<Box<ReturnType>>, FatalParserError> {
+        if !self.parser.is_next(&WideToken::Arrow) {
+            return Ok(None);
+        }
+        let _arrow = self.parser.expect(WideToken::Arrow)?;
+        let type_ = self.parser.parse_type()?;
+        Ok(Some(Box::new(ReturnType { _arrow, type_ })))
+    }
+
     fn parse_function_body(
         &mut self,
     ) -> Result<Vec<Parsed<Statement>>, FatalParserError> {
         let mut body = Vec::new();
-        while !self.is_function_body_end() {
+        while !self.is_declaration_end() {
             let statement = self.parser.parse_statement()?;
             body.push(statement);
         }
         Ok(body)
     }
 
-    fn is_function_body_end(&mut self) -> bool {
+    /// Checks whether the next token is the `end` of a function, record or
+    /// enum.
+    ///
+    /// Unlike statement blocks, these end only at `end`.
+    fn is_declaration_end(&mut self) -> bool {
         self.parser
             .tokens
             .peek()
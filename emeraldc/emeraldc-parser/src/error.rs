@@ -1,4 +1,6 @@
-use emeraldc_lexer::{LexerError, WideToken};
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::{IntegerSuffix, LexerError, WideToken};
+use emeraldc_span::{IntoSpanned, Span, Spanned};
 use serde::{Deserialize, Serialize};
 
 /// Error that breaks parser.
@@ -31,6 +33,39 @@ pub enum NodeError {
     UnexpectedToken(WideToken),
     InvalidStatementIntroducer(WideToken),
     InvalidExpressionIntroducer(WideToken),
+    /// Contains the token found instead of a comma.
+    MissingComma(WideToken),
+    MissingParameterType,
+    MissingFieldType,
+    MissingConstantType,
+    /// `public` before `import`.
+    PublicImport,
+    /// `public` at the end of the file.
+    MissingDeclaration,
+    DuplicateParameter(Symbol),
+    /// Label is not followed by `while`, `loop` or `for`.
+    LabelWithoutLoop,
+    /// Points at the second comparison operator of `a < b < c`.
+    ChainedComparison,
+    /// Left side of an assignment is not a variable or a field, like in
+    /// `1 = x`.
+    InvalidAssignmentTarget,
+    /// Left side of `name = value` in an argument list is not a name.
+    InvalidFieldName,
+    /// Only a record name can be followed by named fields.
+    InvalidRecordName,
+    /// Record construction mixes named and positional fields.
+    MissingFieldName,
+    /// Token can't start a pattern.
+    InvalidPattern(WideToken),
+    /// Points at the `(` of the argument list.
+    UnclosedArgumentList,
+    /// Points at the opening bracket of a generic, tuple or function type.
+    UnclosedTypeList,
+    /// Points at the `(` of a variant pattern.
+    UnclosedPatternList,
+    /// Integer literal does not fit into its suffix type or into 128 bits.
+    IntegerTooLarge(Option<IntegerSuffix>),
     Lexer(LexerError),
 }
 
@@ -38,6 +73,12 @@ impl NodeError {
     pub fn is_had_error(token_kind: &WideToken) -> bool {
         matches!(token_kind, WideToken::HadError(_))
     }
+
+    /// Wraps a lexer error, preferring its own span over the token span.
+    pub fn lexer(error: LexerError, token_span: Span) -> Spanned<Self> {
+        let span = error.span().cloned().unwrap_or(token_span);
+        Self::Lexer(error).into_spanned(span)
+    }
 }
 
 impl std::fmt::Display for NodeError {
@@ -52,6 +93,62 @@ impl std::fmt::Display for NodeError {
             Self::InvalidExpressionIntroducer(token) => {
                 write!(f, "invalid expression introducer: {token}")
             }
+            Self::MissingComma(token) => {
+                write!(f, "expected \x1b[3m','\x1b[m, found {token}")
+            }
+            Self::MissingParameterType => write!(f, "parameter has no type"),
+            Self::MissingFieldType => write!(f, "field has no type"),
+            Self::MissingConstantType => write!(f, "constant has no type"),
+            Self::PublicImport => {
+                write!(f, "imports can't be \x1b[3m'public'\x1b[m")
+            }
+            Self::MissingDeclaration => {
+                write!(f, "expected a declaration after \x1b[3m'public'\x1b[m")
+            }
+            Self::DuplicateParameter(name) => {
+                write!(
+                    f,
+                    "parameter \x1b[3m'{name}'\x1b[m is declared more than once"
+                )
+            }
+            Self::LabelWithoutLoop => {
+                write!(f, "label must be followed by a loop")
+            }
+            Self::ChainedComparison => {
+                write!(
+                    f,
+                    "comparison operators cannot be chained, join the comparisons with \x1b[3m'and'\x1b[m"
+                )
+            }
+            Self::InvalidAssignmentTarget => {
+                write!(f, "only a variable or a field can be assigned to")
+            }
+            Self::InvalidFieldName => write!(f, "expected a field name"),
+            Self::InvalidRecordName => {
+                write!(f, "only a record can be constructed with named fields")
+            }
+            Self::MissingFieldName => {
+                write!(
+                    f,
+                    "field value has no name, expected \x1b[3m'name = value'\x1b[m"
+                )
+            }
+            Self::InvalidPattern(token) => {
+                write!(f, "expected a pattern, found {token}")
+            }
+            Self::UnclosedArgumentList => {
+                write!(f, "argument list is never closed")
+            }
+            Self::UnclosedTypeList => write!(f, "type list is never closed"),
+            Self::UnclosedPatternList => {
+                write!(f, "pattern list is never closed")
+            }
+            Self::IntegerTooLarge(Some(suffix)) => {
+                write!(f, "integer does not fit into \x1b[3m{suffix}\x1b[m")
+            }
+            Self::IntegerTooLarge(None) => {
+                write!(f, "integer does not fit into 128 bits")
+            }
             Self::Lexer(error) => write!(f, "{error}"),
         }
     }
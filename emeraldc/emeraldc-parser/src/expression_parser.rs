@@ -1,9 +1,11 @@
 use emeraldc_lexer::WideToken;
-use emeraldc_span::{IntoSpanned, Span};
+use emeraldc_span::{IntoSpanned, Span, Spanned};
 
 use crate::{
-    Binary, BinaryOperator, Expression, FatalParserError, IntroducerKind,
-    NodeError, Parenthesized, Parsed, Parser, Subparser, span_from_parsed,
+    Argument, ArgumentName, Associativity, Binary, BinaryOperator, Call,
+    Construction, Expression, FatalParserError, FieldAccess, Float, Identifier,
+    Integer, IntroducerKind, NodeError, Parenthesized, Parsed, Parser,
+    Qualifier, Subparser, Unary, UnaryOperator, span_from_parsed,
 };
 
 pub struct ExpressionParser<'p> {
@@ -28,17 +30,34 @@ impl<'p> ExpressionParser<'p> {
         self.parse_with_precedence(0)
     }
 
+    pub(crate) fn parse_literal(
+        parser: &'p mut Parser,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        let mut this = Self::new(parser);
+        match this.parser.tokens.peek().map(|t| &t.value) {
+            Some(WideToken::Integer(_)) => this.parse_integer(),
+            Some(WideToken::Float(_)) => this.parse_float(),
+            Some(WideToken::String(_)) => this.parse_string(),
+            _ => Err(FatalParserError::CompilerBug("not a literal")),
+        }
+    }
+
     fn parse_with_precedence(
         &mut self,
         minimal_precedence: u8,
     ) -> Result<Parsed<Expression>, FatalParserError> {
-        let mut left = self.parse_primary()?;
+        let mut left = self.parse_operand()?;
+        // looser operators take the rest of the chain into their right side,
+        // so a second non-associative operator in this loop is a chain
+        let mut seen_non_associative = false;
         while let Some(operator) = self.peek_binary_operator() {
             let (left_precedence, right_precedence) = operator.precedence();
             if left_precedence < minimal_precedence {
                 break;
             }
-            let operator = self.parse_binary_operator(operator)?;
+            let chained = operator.associativity() == Associativity::None
+                && std::mem::replace(&mut seen_non_associative, true);
+            let operator = self.parse_binary_operator(operator, chained)?;
             let right = self.parse_with_precedence(right_precedence)?;
             let span = self.join_binary_span(&left, &right);
             let binary = Binary {
@@ -52,6 +71,37 @@ impl<'p> ExpressionParser<'p> {
         Ok(left)
     }
 
+    /// Parses a primary expression with its prefix and postfix operators.
+    fn parse_operand(
+        &mut self,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        if let Some(operator) = self.peek_unary_operator() {
+            return self.parse_unary(operator);
+        }
+        let primary = self.parse_primary()?;
+        self.parse_postfix(primary)
+    }
+
+    fn peek_unary_operator(&mut self) -> Option<UnaryOperator> {
+        let token = self.parser.tokens.peek();
+        token.and_then(|t| UnaryOperator::from_token(&t.value))
+    }
+
+    fn parse_unary(
+        &mut self,
+        operator: UnaryOperator,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        let token = self.parser.tokens.next().unwrap();
+        let operand = self.parse_with_precedence(operator.precedence())?;
+        let span = token.span.clone().join(span_from_parsed(&operand));
+        let unary = Unary {
+            operator: Ok(operator.into_spanned(token.span)),
+            operand: Box::new(operand),
+        };
+        let parsed = Ok(Expression::Unary(unary).into_spanned(span));
+        Ok(parsed)
+    }
+
     fn join_binary_span(
         &self,
         left: &Parsed<Expression>,
@@ -70,9 +120,14 @@ impl<'p> ExpressionParser<'p> {
     fn parse_binary_operator(
         &mut self,
         peeked_operator: BinaryOperator,
+        chained: bool,
     ) -> Result<Parsed<BinaryOperator>, FatalParserError> {
         let token = self.parser.tokens.next().unwrap();
-        let parsed = Ok(peeked_operator.into_spanned(token.span));
+        let parsed = if chained {
+            Err(NodeError::ChainedComparison.into_spanned(token.span))
+        } else {
+            Ok(peeked_operator.into_spanned(token.span))
+        };
         Ok(parsed)
     }
 
@@ -90,8 +145,8 @@ impl<'p> ExpressionParser<'p> {
     ) -> Result<Parsed<Expression>, FatalParserError> {
         match self.parser.tokens.next() {
             Some(token) if token.value.had_error() => {
-                let error = Err(NodeError::Lexer(token.value.as_error())
-                    .into_spanned(token.span));
+                let error =
+                    Err(NodeError::lexer(token.value.as_error(), token.span));
                 Ok(error)
             }
             Some(token) => {
@@ -108,15 +163,19 @@ impl<'p> ExpressionParser<'p> {
         &mut self,
     ) -> Result<Parsed<Expression>, FatalParserError> {
         match self.parser.tokens.peek() {
-            Some(token) if token.value == WideToken::Integer => {
-                let token = self.parser.tokens.next().unwrap();
-                let parsed = Ok(Expression::Integer.into_spanned(token.span));
-                Ok(parsed)
+            Some(token) if matches!(token.value, WideToken::Integer(_)) => {
+                self.parse_integer()
             }
-            Some(token) if token.value == WideToken::Identifier => {
+            Some(token) if matches!(token.value, WideToken::Float(_)) => {
+                self.parse_float()
+            }
+            Some(token) if matches!(token.value, WideToken::String(_)) => {
+                self.parse_string()
+            }
+            Some(token) if matches!(token.value, WideToken::Identifier(_)) => {
                 let identifier = self.parser.parse_identifier()?;
-                let parsed = identifier.and_then(|n| {
-                    Ok(Expression::Variable(n.value).into_spanned(n.span))
+                let parsed = identifier.map(|n| {
+                    Expression::Variable(n.value).into_spanned(n.span)
                 });
                 Ok(parsed)
             }
@@ -127,6 +186,228 @@ impl<'p> ExpressionParser<'p> {
         }
     }
 
+    /// Parses calls and field accesses after the operand.
+    ///
+    /// They bind tighter than any operator, so `a + f(b)` calls only `f`.
+    fn parse_postfix(
+        &mut self,
+        mut operand: Parsed<Expression>,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        loop {
+            operand = match self.parser.tokens.peek().map(|t| &t.value) {
+                Some(WideToken::OpenRound) => self.parse_call(operand)?,
+                Some(WideToken::Dot) => self.parse_field_access(operand)?,
+                _ => return Ok(operand),
+            };
+        }
+    }
+
+    fn parse_field_access(
+        &mut self,
+        object: Parsed<Expression>,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        let _dot = self.parser.expect(WideToken::Dot)?;
+        let field = self.parser.parse_identifier()?;
+        let span = span_from_parsed(&object).join(span_from_parsed(&field));
+        let field_access = FieldAccess {
+            object: Box::new(object),
+            _dot,
+            field,
+        };
+        let parsed =
+            Ok(Expression::FieldAccess(field_access).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_call(
+        &mut self,
+        callee: Parsed<Expression>,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        let _open_round = self.parser.expect(WideToken::OpenRound)?;
+        let arguments = self.parse_arguments()?;
+        let _close_round = self.parser.expect_closing(
+            WideToken::CloseRound,
+            &_open_round,
+            NodeError::UnclosedArgumentList,
+        )?;
+        let span =
+            span_from_parsed(&callee).join(span_from_parsed(&_close_round));
+        if arguments.iter().flatten().any(|a| a.value.name.is_some()) {
+            let (qualifier, record) = Self::record_name(callee);
+            let construction = Construction {
+                qualifier,
+                record,
+                _open_round,
+                fields: Self::named_fields(arguments),
+                _close_round,
+            };
+            let parsed =
+                Ok(Expression::Construction(construction).into_spanned(span));
+            return Ok(parsed);
+        }
+        let call = Call {
+            callee: Box::new(callee),
+            _open_round,
+            arguments,
+            _close_round,
+        };
+        let parsed = Ok(Expression::Call(call).into_spanned(span));
+        Ok(parsed)
+    }
+
+    /// Splits the callee into `Record` or `module.Record`.
+    fn record_name(
+        callee: Parsed<Expression>,
+    ) -> (Option<Box<Qualifier>>, Parsed<Identifier>) {
+        let callee = match callee {
+            Ok(callee) => callee,
+            Err(error) => return (None, Err(error)),
+        };
+        let invalid = NodeError::InvalidRecordName;
+        match callee.value {
+            Expression::Variable(identifier) => {
+                (None, Ok(identifier.into_spanned(callee.span)))
+            }
+            Expression::FieldAccess(access) => match *access.object {
+                Ok(Spanned {
+                    value: Expression::Variable(module),
+                    span,
+                }) => {
+                    let qualifier = Qualifier {
+                        module: Ok(module.into_spanned(span)),
+                        _dot: access._dot,
+                    };
+                    (Some(Box::new(qualifier)), access.field)
+                }
+                _ => (None, Err(invalid.into_spanned(callee.span))),
+            },
+            _ => (None, Err(invalid.into_spanned(callee.span))),
+        }
+    }
+
+    fn named_fields(arguments: Vec<Parsed<Argument>>) -> Vec<Parsed<Argument>> {
+        let named = |argument: Spanned<Argument>| match argument.value.name {
+            Some(_) => Ok(argument),
+            None => {
+                Err(NodeError::MissingFieldName.into_spanned(argument.span))
+            }
+        };
+        arguments
+            .into_iter()
+            .map(|argument| argument.and_then(named))
+            .collect()
+    }
+
+    /// Parses arguments while the next token can start an expression.
+    fn parse_arguments(
+        &mut self,
+    ) -> Result<Vec<Parsed<Argument>>, FatalParserError> {
+        let mut arguments = Vec::new();
+        while self.parser.token_introducer_kind() == IntroducerKind::Expression
+        {
+            let argument = self.parse_argument()?;
+            arguments.push(argument);
+        }
+        Ok(arguments)
+    }
+
+    /// Parses `value` or a named `name = value`.
+    fn parse_argument(&mut self) -> Result<Parsed<Argument>, FatalParserError> {
+        let first = self.parser.parse_expression()?;
+        let mut span = span_from_parsed(&first);
+        let (name, value) = if self.parser.is_next(&WideToken::Equal) {
+            let identifier = first.and_then(|name| match name.value {
+                Expression::Variable(identifier) => {
+                    Ok(identifier.into_spanned(name.span))
+                }
+                _ => Err(NodeError::InvalidFieldName.into_spanned(name.span)),
+            });
+            let _equal = self.parser.expect(WideToken::Equal)?;
+            let value = self.parser.parse_expression()?;
+            span = span.join(span_from_parsed(&value));
+            (Some(ArgumentName { identifier, _equal }), value)
+        } else {
+            (None, first)
+        };
+        let _comma = self.parse_argument_comma()?;
+        let argument = Argument {
+            name,
+            value,
+            _comma,
+        };
+        Ok(Ok(argument.into_spanned(span)))
+    }
+
+    /// Comma is optional before the closing round bracket.
+    ///
+    /// If the next token can't continue the list, the comma is not reported
+    /// as missing: the list is unclosed instead.
+    fn parse_argument_comma(
+        &mut self,
+    ) -> Result<Option<Parsed<WideToken>>, FatalParserError> {
+        if self.parser.is_next(&WideToken::Comma) {
+            let comma = self.parser.expect(WideToken::Comma)?;
+            return Ok(Some(comma));
+        }
+        if self.parser.token_introducer_kind() != IntroducerKind::Expression {
+            return Ok(None);
+        }
+        // the token is left for the next argument
+        let token = self.parser.tokens.peek().unwrap();
+        let error = NodeError::MissingComma(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Some(Err(error)))
+    }
+
+    fn parse_integer(
+        &mut self,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        let token = self.parser.tokens.next().unwrap();
+        let WideToken::Integer(literal) = token.value else {
+            unreachable!()
+        };
+        let value =
+            u128::from_str_radix(literal.digits.as_str(), literal.base.radix())
+                .ok()
+                .filter(|value| {
+                    literal.suffix.is_none_or(|s| *value <= s.max_value())
+                });
+        let parsed = match value {
+            Some(value) => {
+                let integer = Integer {
+                    value,
+                    suffix: literal.suffix,
+                };
+                Ok(Expression::Integer(integer).into_spanned(token.span))
+            }
+            None => Err(NodeError::IntegerTooLarge(literal.suffix)
+                .into_spanned(token.span)),
+        };
+        Ok(parsed)
+    }
+
+    fn parse_string(&mut self) -> Result<Parsed<Expression>, FatalParserError> {
+        let token = self.parser.tokens.next().unwrap();
+        let WideToken::String(symbol) = token.value else {
+            unreachable!()
+        };
+        let parsed = Ok(Expression::String(symbol).into_spanned(token.span));
+        Ok(parsed)
+    }
+
+    fn parse_float(&mut self) -> Result<Parsed<Expression>, FatalParserError> {
+        let token = self.parser.tokens.next().unwrap();
+        let WideToken::Float(literal) = token.value else {
+            unreachable!()
+        };
+        let float = Float {
+            value: literal.digits.as_str().parse().unwrap(), // validated by the lexer
+            suffix: literal.suffix,
+        };
+        let parsed = Ok(Expression::Float(float).into_spanned(token.span));
+        Ok(parsed)
+    }
+
     fn parse_parenthesized(
         &mut self,
     ) -> Result<Parsed<Expression>, FatalParserError> {
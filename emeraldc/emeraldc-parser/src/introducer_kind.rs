@@ -17,11 +17,31 @@ pub enum IntroducerKind {
 impl From<&WideToken> for IntroducerKind {
     fn from(token_kind: &WideToken) -> Self {
         match token_kind {
-            WideToken::FunctionKeyword => Self::Declaration,
-            WideToken::LetKeyword => Self::Statement,
-            WideToken::Identifier
-            | WideToken::Integer
-            | WideToken::OpenRound => Self::Expression,
+            WideToken::FunctionKeyword
+            | WideToken::RecordKeyword
+            | WideToken::EnumKeyword
+            | WideToken::ImportKeyword
+            | WideToken::ConstKeyword
+            | WideToken::PublicKeyword => Self::Declaration,
+            WideToken::LetKeyword
+            | WideToken::ReturnKeyword
+            | WideToken::IfKeyword
+            | WideToken::MatchKeyword
+            | WideToken::WhileKeyword
+            | WideToken::LoopKeyword
+            | WideToken::ForKeyword
+            | WideToken::BreakKeyword
+            | WideToken::ContinueKeyword
+            | WideToken::At => Self::Statement,
+            WideToken::Identifier(_)
+            | WideToken::Integer(_)
+            | WideToken::Float(_)
+            | WideToken::String(_)
+            | WideToken::OpenRound
+            | WideToken::Minus
+            | WideToken::Plus
+            | WideToken::Tilde
+            | WideToken::NotKeyword => Self::Expression,
             _ => Self::Other,
         }
     }
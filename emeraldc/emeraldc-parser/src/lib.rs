@@ -3,12 +3,16 @@ mod error;
 mod expression_parser;
 mod introducer_kind;
 mod parser;
+mod pattern_parser;
 mod statement_parser;
 mod tree;
+mod type_parser;
 use declaration_parser::*;
 pub use error::*;
 use expression_parser::*;
 use introducer_kind::*;
 pub use parser::*;
+use pattern_parser::*;
 use statement_parser::*;
 pub use tree::*;
+use type_parser::*;
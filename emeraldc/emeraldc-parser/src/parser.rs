@@ -1,16 +1,21 @@
+use std::collections::HashMap;
 use std::iter::Peekable;
 
+use emeraldc_interner::Symbol;
 use emeraldc_lexer::WideToken;
-use emeraldc_span::{IntoSpanned, Spanned};
+use emeraldc_span::{IntoSpanned, Span, Spanned};
 
 use crate::{
     Declaration, DeclarationParser, Expression, ExpressionParser,
-    FatalParserError, Identifier, IntroducerKind, NodeError, Parsed, Statement,
-    StatementParser,
+    FatalParserError, Identifier, IntroducerKind, NodeError, Parsed, Pattern,
+    PatternParser, Statement, StatementParser, TypeAnnotation, TypeExpr,
+    TypeParser, span_from_parsed,
 };
 
 pub struct Parser {
     pub(crate) tokens: Peekable<std::vec::IntoIter<Spanned<WideToken>>>,
+    /// Doc comments, keyed by the start of the token they precede.
+    docs: HashMap<usize, Vec<Spanned<Symbol>>>,
 }
 
 impl Parser {
@@ -26,12 +31,31 @@ impl Parser {
     }
 
     fn new(tokens: impl Iterator<Item = Spanned<WideToken>>) -> Self {
-        let tokens = tokens.filter(|t| {
-            !matches!(t.value, WideToken::Invisible | WideToken::Comment)
-        });
-        let tokens = tokens.collect::<Vec<_>>().into_iter();
-        let tokens = tokens.peekable();
-        Self { tokens }
+        let mut significant = Vec::new();
+        let mut docs = HashMap::new();
+        let mut pending_docs = Vec::new();
+        for token in tokens {
+            match token.value {
+                WideToken::Invisible | WideToken::Comment => {}
+                WideToken::DocComment(text) => {
+                    pending_docs.push(text.into_spanned(token.span))
+                }
+                _ => {
+                    if !pending_docs.is_empty() {
+                        let attached = std::mem::take(&mut pending_docs);
+                        docs.insert(token.span.start, attached);
+                    }
+                    significant.push(token);
+                }
+            }
+        }
+        let tokens = significant.into_iter().peekable();
+        Self { tokens, docs }
+    }
+
+    /// Takes doc comments written right before the token with the given span.
+    pub(crate) fn take_docs(&mut self, span: &Span) -> Vec<Spanned<Symbol>> {
+        self.docs.remove(&span.start).unwrap_or_default()
     }
 
     pub(crate) fn parse_declaration(
@@ -49,23 +73,116 @@ impl Parser {
         &mut self,
     ) -> Result<Parsed<Statement>, FatalParserError> {
         let statement = StatementParser::parse(self);
-        self.synchronize(|this| {
-            this.tokens.peek().is_none()
-                || this.token_introducer_kind() == IntroducerKind::Statement
-                || this
-                    .tokens
-                    .peek()
-                    .is_some_and(|s| s.value == WideToken::EndKeyword)
-        });
+        // the rest of a broken statement is skipped up to the next statement
+        // keyword, so that it doesn't become statements of its own
+        if !matches!(statement, Ok(Ok(_))) {
+            self.synchronize(|this| {
+                this.tokens.peek().is_none()
+                    || this.token_introducer_kind() == IntroducerKind::Statement
+                    || this.is_block_end()
+            });
+        }
         statement
     }
 
+    /// Checks whether the next token ends a block of statements.
+    pub(crate) fn is_block_end(&mut self) -> bool {
+        self.tokens.peek().is_some_and(|t| {
+            matches!(
+                t.value,
+                WideToken::EndKeyword
+                    | WideToken::ElifKeyword
+                    | WideToken::ElseKeyword
+                    | WideToken::CaseKeyword
+            )
+        })
+    }
+
     pub(crate) fn parse_expression(
         &mut self,
     ) -> Result<Parsed<Expression>, FatalParserError> {
         ExpressionParser::parse(self)
     }
 
+    pub(crate) fn parse_pattern(
+        &mut self,
+    ) -> Result<Parsed<Pattern>, FatalParserError> {
+        PatternParser::parse(self)
+    }
+
+    /// Parses an integer, float or string literal.
+    pub(crate) fn parse_literal(
+        &mut self,
+    ) -> Result<Parsed<Expression>, FatalParserError> {
+        ExpressionParser::parse_literal(self)
+    }
+
+    pub(crate) fn parse_type(
+        &mut self,
+    ) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        TypeParser::parse(self)
+    }
+
+    /// Parses `: Type`.
+    pub(crate) fn parse_type_annotation(
+        &mut self,
+    ) -> Result<Parsed<TypeAnnotation>, FatalParserError> {
+        let _colon = self.expect(WideToken::Colon)?;
+        let type_ = self.parse_type()?;
+        let span = span_from_parsed(&_colon).join(span_from_parsed(&type_));
+        let annotation = TypeAnnotation { _colon, type_ };
+        Ok(Ok(annotation.into_spanned(span)))
+    }
+
+    /// Parses comma-separated elements while `is_start` accepts the next
+    /// token, wrapping each one with the comma after it.
+    ///
+    /// The closing bracket itself is left to the caller.
+    pub(crate) fn parse_comma_separated<E, T>(
+        &mut self,
+        close: &WideToken,
+        is_start: fn(&WideToken) -> bool,
+        parse: fn(&mut Self) -> Result<Parsed<E>, FatalParserError>,
+        wrap: fn(Parsed<E>, Option<Parsed<WideToken>>) -> T,
+    ) -> Result<Vec<Parsed<T>>, FatalParserError> {
+        let mut elements = Vec::new();
+        while self.tokens.peek().is_some_and(|t| is_start(&t.value)) {
+            let element = parse(self)?;
+            let span = span_from_parsed(&element);
+            let comma = self.parse_element_comma(close, is_start)?;
+            elements.push(Ok(wrap(element, comma).into_spanned(span)));
+        }
+        Ok(elements)
+    }
+
+    /// Comma is optional only before the closing bracket.
+    fn parse_element_comma(
+        &mut self,
+        close: &WideToken,
+        is_start: fn(&WideToken) -> bool,
+    ) -> Result<Option<Parsed<WideToken>>, FatalParserError> {
+        if self.is_next(&WideToken::Comma) {
+            let comma = self.expect(WideToken::Comma)?;
+            return Ok(Some(comma));
+        }
+        // a missing bracket is reported by the caller
+        let Some(token) = self.tokens.peek() else {
+            return Ok(None);
+        };
+        if token.value == *close || !is_start(&token.value) {
+            return Ok(None);
+        }
+        // the token is left for the next element
+        let error = NodeError::MissingComma(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Some(Err(error)))
+    }
+
+    /// Checks the next token without consuming it.
+    pub(crate) fn is_next(&mut self, kind: &WideToken) -> bool {
+        self.tokens.peek().is_some_and(|t| t.value == *kind)
+    }
+
     pub(crate) fn token_introducer_kind(&mut self) -> IntroducerKind {
         match self.tokens.peek() {
             Some(token) => IntroducerKind::from(&token.value),
@@ -77,13 +194,16 @@ impl Parser {
         &mut self,
     ) -> Result<Parsed<Identifier>, FatalParserError> {
         match self.tokens.next() {
-            Some(token) if token.value == WideToken::Identifier => {
-                let parsed = Ok(Identifier.into_spanned(token.span));
+            Some(Spanned {
+                value: WideToken::Identifier(symbol),
+                span,
+            }) => {
+                let parsed = Ok(Identifier { symbol }.into_spanned(span));
                 Ok(parsed)
             }
             Some(token) if token.value.had_error() => {
-                let error = Err(NodeError::Lexer(token.value.as_error())
-                    .into_spanned(token.span));
+                let error =
+                    Err(NodeError::lexer(token.value.as_error(), token.span));
                 Ok(error)
             }
             Some(token) => {
@@ -105,8 +225,8 @@ impl Parser {
                 Ok(parsed)
             }
             Some(token) if token.value.had_error() => {
-                let error = Err(NodeError::Lexer(token.value.as_error())
-                    .into_spanned(token.span));
+                let error =
+                    Err(NodeError::lexer(token.value.as_error(), token.span));
                 Ok(error)
             }
             Some(token) => {
@@ -118,6 +238,22 @@ impl Parser {
         }
     }
 
+    /// Expects the closing bracket, otherwise reports the error at the opening
+    /// one.
+    ///
+    /// The unexpected token is not consumed, so it may end the construct.
+    pub(crate) fn expect_closing(
+        &mut self,
+        kind: WideToken,
+        open: &Parsed<WideToken>,
+        unclosed: NodeError,
+    ) -> Result<Parsed<WideToken>, FatalParserError> {
+        if self.is_next(&kind) {
+            return self.expect(kind);
+        }
+        Ok(Err(unclosed.into_spanned(span_from_parsed(open))))
+    }
+
     fn synchronize(&mut self, stop: impl Fn(&mut Self) -> bool) {
         while !stop(self) {
             self.tokens.next().unwrap();
@@ -128,3 +264,54 @@ impl Parser {
 pub trait Subparser<'p, T> {
     fn parse(parser: &'p mut Parser) -> Result<Parsed<T>, FatalParserError>;
 }
+
+#[cfg(test)]
+mod tests {
+    use emeraldc_lexer::Lexer;
+    use emeraldc_span::SourceMap;
+    use emeraldc_tokenizer::Tokenizer;
+
+    use super::*;
+
+    fn parse_body(source: &str) -> Vec<Parsed<Statement>> {
+        let mut source_map = SourceMap::new();
+        let file = source_map.add_file("test.ed", source);
+        let file = source_map.file(file);
+        let tokens = Lexer::lex(file, Tokenizer::tokenize(file.source()));
+        let declaration = Parser::parse(tokens).next().unwrap().unwrap();
+        match declaration.unwrap().value {
+            Declaration::Function(function) => function.body,
+            declaration => panic!("expected a function, found {declaration:?}"),
+        }
+    }
+
+    #[test]
+    fn consecutive_expression_statements() {
+        let body = parse_body(
+            "function f()\n    print(1)\n    1 + 2\n    x = 1\n    \
+             let y = 2\n    3 + 4\nend\n",
+        );
+        let statements = body
+            .into_iter()
+            .map(|statement| statement.unwrap().value)
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            statements.as_slice(),
+            [
+                Statement::Expression(Expression::Call(_)),
+                Statement::Expression(Expression::Binary(_)),
+                Statement::Assignment(_),
+                Statement::Let(_),
+                Statement::Expression(Expression::Binary(_)),
+            ]
+        ));
+    }
+
+    #[test]
+    fn broken_statement_is_skipped() {
+        let body = parse_body("function f()\n    ) 1 2\n    let y = 1\nend\n");
+        assert_eq!(body.len(), 2);
+        assert!(body[0].is_err());
+        assert!(matches!(body[1].as_ref().unwrap().value, Statement::Let(_)));
+    }
+}
@@ -0,0 +1,134 @@
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::WideToken;
+use emeraldc_span::IntoSpanned;
+
+use crate::{
+    Expression, FatalParserError, NodeError, Parsed, Parser, Pattern,
+    PatternElement, Subparser, Unary, UnaryOperator, VariantPattern,
+    span_from_parsed,
+};
+
+pub struct PatternParser<'p> {
+    parser: &'p mut Parser,
+}
+
+impl<'p> Subparser<'p, Pattern> for PatternParser<'p> {
+    fn parse(
+        parser: &'p mut Parser,
+    ) -> Result<Parsed<Pattern>, FatalParserError> {
+        let this = Self::new(parser);
+        this.parse()
+    }
+}
+
+impl<'p> PatternParser<'p> {
+    fn new(parser: &'p mut Parser) -> Self {
+        Self { parser }
+    }
+
+    fn parse(mut self) -> Result<Parsed<Pattern>, FatalParserError> {
+        match self.parser.tokens.peek().map(|t| &t.value) {
+            Some(token) if token.had_error() => self.parse_named(),
+            Some(WideToken::Identifier(_)) => self.parse_named(),
+            Some(
+                WideToken::Integer(_)
+                | WideToken::Float(_)
+                | WideToken::String(_),
+            ) => self.parse_literal(),
+            Some(WideToken::Minus) => self.parse_negative_literal(),
+            Some(_) => self.invalid_pattern(),
+            None => Err(FatalParserError::UnexpectedEof),
+        }
+    }
+
+    /// Reports the token without consuming it, so a missing pattern doesn't
+    /// swallow the first statement of the arm.
+    fn invalid_pattern(&mut self) -> Result<Parsed<Pattern>, FatalParserError> {
+        let token = self.parser.tokens.peek().unwrap();
+        let error = NodeError::InvalidPattern(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Err(error))
+    }
+
+    fn parse_literal(&mut self) -> Result<Parsed<Pattern>, FatalParserError> {
+        let literal = match self.parser.parse_literal()? {
+            Ok(literal) => literal,
+            Err(error) => return Ok(Err(error)),
+        };
+        let pattern = Pattern::Literal(literal.value);
+        Ok(Ok(pattern.into_spanned(literal.span)))
+    }
+
+    /// Parses `-` followed by an integer or float literal.
+    fn parse_negative_literal(
+        &mut self,
+    ) -> Result<Parsed<Pattern>, FatalParserError> {
+        let minus = self.parser.tokens.next().unwrap();
+        match self.parser.tokens.peek().map(|t| &t.value) {
+            Some(WideToken::Integer(_) | WideToken::Float(_)) => {}
+            Some(_) => return self.invalid_pattern(),
+            None => return Err(FatalParserError::UnexpectedEof),
+        }
+        let literal = match self.parser.parse_literal()? {
+            Ok(literal) => literal,
+            Err(error) => return Ok(Err(error)),
+        };
+        let span = minus.span.clone().join(literal.span.clone());
+        let unary = Unary {
+            operator: Ok(UnaryOperator::Negate.into_spanned(minus.span)),
+            operand: Box::new(Ok(literal)),
+        };
+        let pattern = Pattern::Literal(Expression::Unary(unary));
+        Ok(Ok(pattern.into_spanned(span)))
+    }
+
+    /// Parses `_`, a binding or a variant pattern `Name(Pattern, ...)`.
+    fn parse_named(&mut self) -> Result<Parsed<Pattern>, FatalParserError> {
+        let identifier = match self.parser.parse_identifier()? {
+            Ok(identifier) => identifier,
+            Err(error) => return Ok(Err(error)),
+        };
+        if !self.parser.is_next(&WideToken::OpenRound) {
+            let pattern = if identifier.value.symbol == Symbol::intern("_") {
+                Pattern::Wildcard
+            } else {
+                Pattern::Binding(identifier.value)
+            };
+            return Ok(Ok(pattern.into_spanned(identifier.span)));
+        }
+        let _open_round = self.parser.expect(WideToken::OpenRound)?;
+        let fields = self.parser.parse_comma_separated(
+            &WideToken::CloseRound,
+            Self::is_pattern_start,
+            Parser::parse_pattern,
+            |pattern, _comma| PatternElement { pattern, _comma },
+        )?;
+        let _close_round = self.parser.expect_closing(
+            WideToken::CloseRound,
+            &_open_round,
+            NodeError::UnclosedPatternList,
+        )?;
+        let span = identifier
+            .span
+            .clone()
+            .join(span_from_parsed(&_close_round));
+        let variant = VariantPattern {
+            identifier: Ok(identifier),
+            _open_round,
+            fields,
+            _close_round,
+        };
+        Ok(Ok(Pattern::Variant(variant).into_spanned(span)))
+    }
+
+    fn is_pattern_start(token: &WideToken) -> bool {
+        matches!(
+            token,
+            WideToken::Identifier(_)
+                | WideToken::Integer(_)
+                | WideToken::Float(_)
+                | WideToken::String(_)
+                | WideToken::Minus
+        )
+    }
+}
@@ -1,9 +1,11 @@
 use emeraldc_lexer::WideToken;
-use emeraldc_span::IntoSpanned;
+use emeraldc_span::{IntoSpanned, Span};
 
 use crate::{
-    FatalParserError, IntroducerKind, Let, NodeError, Parsed, Parser,
-    Statement, Subparser, span_from_parsed,
+    Assignment, AssignmentOperator, Break, Continue, Elif, Else, Expression,
+    FatalParserError, For, If, IntroducerKind, Label, Let, Loop, Match,
+    MatchArm, NodeError, Parsed, Parser, Return, Statement, Subparser, While,
+    span_from_parsed,
 };
 
 pub struct StatementParser<'p> {
@@ -27,6 +29,7 @@ impl<'p> StatementParser<'p> {
     fn parse(mut self) -> Result<Parsed<Statement>, FatalParserError> {
         match self.parser.token_introducer_kind() {
             IntroducerKind::Statement => self.parse_unchecked(),
+            IntroducerKind::Expression => self.parse_expression_statement(),
             _ => self.invalid_introducer(),
         }
     }
@@ -36,8 +39,8 @@ impl<'p> StatementParser<'p> {
     ) -> Result<Parsed<Statement>, FatalParserError> {
         match self.parser.tokens.next() {
             Some(token) if token.value.had_error() => {
-                let error = Err(NodeError::Lexer(token.value.as_error())
-                    .into_spanned(token.span));
+                let error =
+                    Err(NodeError::lexer(token.value.as_error(), token.span));
                 Ok(error)
             }
             Some(token) => {
@@ -53,6 +56,15 @@ impl<'p> StatementParser<'p> {
     fn parse_unchecked(self) -> Result<Parsed<Statement>, FatalParserError> {
         match self.parser.tokens.peek().unwrap().value {
             WideToken::LetKeyword => self.parse_let(),
+            WideToken::ReturnKeyword => self.parse_return(),
+            WideToken::IfKeyword => self.parse_if(),
+            WideToken::MatchKeyword => self.parse_match(),
+            WideToken::WhileKeyword => self.parse_while(None),
+            WideToken::LoopKeyword => self.parse_loop(None),
+            WideToken::ForKeyword => self.parse_for(None),
+            WideToken::BreakKeyword => self.parse_break(),
+            WideToken::ContinueKeyword => self.parse_continue(),
+            WideToken::At => self.parse_labeled(),
             _ => Err(FatalParserError::CompilerBug("unreachable variant")),
         }
     }
@@ -61,12 +73,18 @@ impl<'p> StatementParser<'p> {
         let _introducer = self.parser.expect(WideToken::LetKeyword)?;
         let introducer_span = span_from_parsed(&_introducer); // todo: full span
         let identifier = self.parser.parse_identifier()?;
+        let annotation = if self.parser.is_next(&WideToken::Colon) {
+            Some(Box::new(self.parser.parse_type_annotation()?))
+        } else {
+            None
+        };
         let _equal = self.parser.expect(WideToken::Equal)?;
         let value = self.parser.parse_expression()?;
         let value_span = span_from_parsed(&value);
         let let_ = Let {
             _introducer,
             identifier,
+            annotation,
             _equal,
             value,
         };
@@ -74,4 +92,287 @@ impl<'p> StatementParser<'p> {
         let parsed = Ok(Statement::Let(let_).into_spanned(span));
         Ok(parsed)
     }
+
+    /// Parses an assignment or a bare expression, whose value is discarded.
+    fn parse_expression_statement(
+        mut self,
+    ) -> Result<Parsed<Statement>, FatalParserError> {
+        let target = self.parser.parse_expression()?;
+        let Some(operator) = self.peek_assignment_operator() else {
+            let parsed = target.map(|expression| {
+                Statement::Expression(expression.value)
+                    .into_spanned(expression.span)
+            });
+            return Ok(parsed);
+        };
+        let target = target.and_then(|target| match target.value {
+            Expression::Variable(_) | Expression::FieldAccess(_) => Ok(target),
+            _ => {
+                Err(NodeError::InvalidAssignmentTarget
+                    .into_spanned(target.span))
+            }
+        });
+        let token = self.parser.tokens.next().unwrap();
+        let operator = Ok(operator.into_spanned(token.span));
+        let value = self.parser.parse_expression()?;
+        let span = span_from_parsed(&target).join(span_from_parsed(&value));
+        let assignment = Assignment {
+            target,
+            operator,
+            value,
+        };
+        let parsed = Ok(Statement::Assignment(assignment).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn peek_assignment_operator(&mut self) -> Option<AssignmentOperator> {
+        let token = self.parser.tokens.peek();
+        token.and_then(|t| AssignmentOperator::from_token(&t.value))
+    }
+
+    fn parse_return(self) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ReturnKeyword)?;
+        let mut span = span_from_parsed(&_introducer);
+        let value = match self.parser.token_introducer_kind() {
+            IntroducerKind::Expression => {
+                let value = self.parser.parse_expression()?;
+                span = span.join(span_from_parsed(&value));
+                Some(value)
+            }
+            _ => None,
+        };
+        let return_ = Return { _introducer, value };
+        let parsed = Ok(Statement::Return(return_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_if(mut self) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::IfKeyword)?;
+        let condition = self.parser.parse_expression()?;
+        let body = self.parse_block()?;
+        let mut elifs = Vec::new();
+        while self.parser.is_next(&WideToken::ElifKeyword) {
+            elifs.push(self.parse_elif()?);
+        }
+        let else_ = if self.parser.is_next(&WideToken::ElseKeyword) {
+            Some(self.parse_else()?)
+        } else {
+            None
+        };
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = span_from_parsed(&_introducer).join(span_from_parsed(&_end));
+        let if_ = If {
+            _introducer,
+            condition,
+            body,
+            elifs,
+            else_,
+            _end,
+        };
+        let parsed = Ok(Statement::If(if_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_elif(&mut self) -> Result<Elif, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ElifKeyword)?;
+        let condition = self.parser.parse_expression()?;
+        let body = self.parse_block()?;
+        Ok(Elif {
+            _introducer,
+            condition,
+            body,
+        })
+    }
+
+    fn parse_else(&mut self) -> Result<Else, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ElseKeyword)?;
+        let body = self.parse_block()?;
+        Ok(Else { _introducer, body })
+    }
+
+    fn parse_match(mut self) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::MatchKeyword)?;
+        let scrutinee = self.parser.parse_expression()?;
+        let mut arms = Vec::new();
+        while self.parser.is_next(&WideToken::CaseKeyword) {
+            arms.push(self.parse_match_arm()?);
+        }
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = span_from_parsed(&_introducer).join(span_from_parsed(&_end));
+        let match_ = Match {
+            _introducer,
+            scrutinee,
+            arms,
+            _end,
+        };
+        let parsed = Ok(Statement::Match(match_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_match_arm(&mut self) -> Result<MatchArm, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::CaseKeyword)?;
+        let pattern = self.parser.parse_pattern()?;
+        let body = self.parse_block()?;
+        Ok(MatchArm {
+            _introducer,
+            pattern,
+            body,
+        })
+    }
+
+    /// Parses a loop that starts with a label.
+    fn parse_labeled(mut self) -> Result<Parsed<Statement>, FatalParserError> {
+        let label = self.parse_label()?;
+        match self.parser.tokens.peek().map(|t| &t.value) {
+            Some(WideToken::WhileKeyword) => self.parse_while(Some(label)),
+            Some(WideToken::LoopKeyword) => self.parse_loop(Some(label)),
+            Some(WideToken::ForKeyword) => self.parse_for(Some(label)),
+            _ => {
+                let span = span_from_parsed(&label);
+                Ok(Err(NodeError::LabelWithoutLoop.into_spanned(span)))
+            }
+        }
+    }
+
+    fn parse_while(
+        mut self,
+        label: Option<Parsed<Label>>,
+    ) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::WhileKeyword)?;
+        let condition = self.parser.parse_expression()?;
+        let body = self.parse_block()?;
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = Self::loop_span(&label, &_introducer, &_end);
+        let while_ = While {
+            label,
+            _introducer,
+            condition,
+            body,
+            _end,
+        };
+        let parsed = Ok(Statement::While(while_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_loop(
+        mut self,
+        label: Option<Parsed<Label>>,
+    ) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::LoopKeyword)?;
+        let body = self.parse_block()?;
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = Self::loop_span(&label, &_introducer, &_end);
+        let loop_ = Loop {
+            label,
+            _introducer,
+            body,
+            _end,
+        };
+        let parsed = Ok(Statement::Loop(loop_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_for(
+        mut self,
+        label: Option<Parsed<Label>>,
+    ) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ForKeyword)?;
+        let variable = self.parser.parse_identifier()?;
+        let _in = self.parser.expect(WideToken::InKeyword)?;
+        let start = self.parser.parse_expression()?;
+        let _double_dot = self.parser.expect(WideToken::DoubleDot)?;
+        let end = self.parser.parse_expression()?;
+        let body = self.parse_block()?;
+        let _end = self.parser.expect(WideToken::EndKeyword)?;
+        let span = Self::loop_span(&label, &_introducer, &_end);
+        let for_ = For {
+            label,
+            _introducer,
+            variable,
+            _in,
+            start: Box::new(start),
+            _double_dot,
+            end: Box::new(end),
+            body,
+            _end,
+        };
+        let parsed = Ok(Statement::For(for_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    /// Span of a loop from its label, if any, to its `end`.
+    fn loop_span(
+        label: &Option<Parsed<Label>>,
+        introducer: &Parsed<WideToken>,
+        end: &Parsed<WideToken>,
+    ) -> Span {
+        let start = match label {
+            Some(label) => span_from_parsed(label),
+            None => span_from_parsed(introducer),
+        };
+        start.join(span_from_parsed(end))
+    }
+
+    fn parse_break(mut self) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::BreakKeyword)?;
+        let label = self.parse_jump_label()?;
+        let span = Self::jump_span(&_introducer, &label);
+        let break_ = Break { _introducer, label };
+        let parsed = Ok(Statement::Break(break_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    fn parse_continue(mut self) -> Result<Parsed<Statement>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::ContinueKeyword)?;
+        let label = self.parse_jump_label()?;
+        let span = Self::jump_span(&_introducer, &label);
+        let continue_ = Continue { _introducer, label };
+        let parsed = Ok(Statement::Continue(continue_).into_spanned(span));
+        Ok(parsed)
+    }
+
+    /// Parses the optional label after `break` or `continue`.
+    ///
+    /// A labeled loop right after the jump would be unreachable, so `@`
+    /// always belongs to the jump.
+    fn parse_jump_label(
+        &mut self,
+    ) -> Result<Option<Parsed<Label>>, FatalParserError> {
+        if self.parser.is_next(&WideToken::At) {
+            Ok(Some(self.parse_label()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn jump_span(
+        introducer: &Parsed<WideToken>,
+        label: &Option<Parsed<Label>>,
+    ) -> Span {
+        let span = span_from_parsed(introducer);
+        match label {
+            Some(label) => span.join(span_from_parsed(label)),
+            None => span,
+        }
+    }
+
+    fn parse_label(&mut self) -> Result<Parsed<Label>, FatalParserError> {
+        let _at = self.parser.expect(WideToken::At)?;
+        let identifier = self.parser.parse_identifier()?;
+        let span = span_from_parsed(&_at).join(span_from_parsed(&identifier));
+        let label = Label { _at, identifier };
+        Ok(Ok(label.into_spanned(span)))
+    }
+
+    /// Parses statements up to `elif`, `else`, `case` or `end`.
+    fn parse_block(
+        &mut self,
+    ) -> Result<Vec<Parsed<Statement>>, FatalParserError> {
+        let mut block = Vec::new();
+        while !self.parser.is_block_end() {
+            let statement = self.parser.parse_statement()?;
+            block.push(statement);
+        }
+        Ok(block)
+    }
 }
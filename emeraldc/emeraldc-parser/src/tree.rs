@@ -1,4 +1,5 @@
-use emeraldc_lexer::WideToken;
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::{FloatSuffix, IntegerSuffix, WideToken};
 use emeraldc_span::{Span, Spanned};
 use serde::{Deserialize, Serialize};
 
@@ -7,40 +8,419 @@ use crate::NodeError;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Declaration {
     Function(Function),
+    Record(Record),
+    Enum(Enum),
+    Const(Const),
+    Import(Import),
+}
+
+/// `const NAME: Type = value` declaration.
+///
+/// The value is evaluated at compile time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Const {
+    /// Lines of `##` comments written before the constant.
+    pub docs: Vec<Spanned<Symbol>>,
+    /// `public` modifier, missing for items private to their module.
+    pub public: Option<Parsed<WideToken>>,
+    pub _introducer: Parsed<WideToken>,
+    pub identifier: Parsed<Identifier>,
+    pub annotation: Box<Parsed<TypeAnnotation>>,
+    pub _equal: Parsed<WideToken>,
+    pub value: Parsed<Expression>,
+}
+
+/// `import path.to.module` declaration.
+///
+/// Items of the module are then named by the last segment of the path, like
+/// `module.item`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Import {
+    /// Always an error, because imports can't be public.
+    pub public: Option<Parsed<WideToken>>,
+    pub _introducer: Parsed<WideToken>,
+    pub segments: Vec<PathSegment>,
+}
+
+/// Segment of a module path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathSegment {
+    /// Missing only before the first segment.
+    pub _dot: Option<Parsed<WideToken>>,
+    pub identifier: Parsed<Identifier>,
+}
+
+/// `module.` before an item declared in an imported module.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Qualifier {
+    pub module: Parsed<Identifier>,
+    pub _dot: Parsed<WideToken>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Function {
+    /// Lines of `##` comments written before the function.
+    pub docs: Vec<Spanned<Symbol>>,
+    /// `public` modifier, missing for items private to their module.
+    pub public: Option<Parsed<WideToken>>,
     pub _introducer: Parsed<WideToken>,
     pub identifier: Parsed<Identifier>,
     pub _open_round: Parsed<WideToken>,
+    pub parameters: Vec<Parsed<Parameter>>,
     pub _close_round: Parsed<WideToken>,
+    pub return_type: Option<Box<ReturnType>>,
     pub body: Vec<Parsed<Statement>>,
     pub _end: Parsed<WideToken>,
 }
 
+/// `record Name field: Type ... end` declaration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    /// Lines of `##` comments written before the record.
+    pub docs: Vec<Spanned<Symbol>>,
+    /// `public` modifier, missing for items private to their module.
+    pub public: Option<Parsed<WideToken>>,
+    pub _introducer: Parsed<WideToken>,
+    pub identifier: Parsed<Identifier>,
+    pub fields: Vec<Parsed<Field>>,
+    pub _end: Parsed<WideToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Field {
+    pub identifier: Parsed<Identifier>,
+    pub annotation: Parsed<TypeAnnotation>,
+}
+
+/// `enum Name Variant ... end` declaration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Enum {
+    /// Lines of `##` comments written before the enum.
+    pub docs: Vec<Spanned<Symbol>>,
+    /// `public` modifier, missing for items private to their module.
+    pub public: Option<Parsed<WideToken>>,
+    pub _introducer: Parsed<WideToken>,
+    pub identifier: Parsed<Identifier>,
+    pub variants: Vec<Parsed<Variant>>,
+    pub _end: Parsed<WideToken>,
+}
+
+/// `Name` or `Name(Type, ...)` variant of an enum.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Variant {
+    pub identifier: Parsed<Identifier>,
+    /// Types of the payload, written as a tuple type.
+    pub payload: Option<Parsed<TypeExpr>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Parameter {
+    pub identifier: Parsed<Identifier>,
+    pub annotation: Parsed<TypeAnnotation>,
+    /// Missing only after the last parameter.
+    pub _comma: Option<Parsed<WideToken>>,
+}
+
+/// `: Type` part of a parameter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeAnnotation {
+    pub _colon: Parsed<WideToken>,
+    pub type_: Parsed<TypeExpr>,
+}
+
+/// `-> Type` part of a function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnType {
+    pub _arrow: Parsed<WideToken>,
+    pub type_: Parsed<TypeExpr>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TypeExpr {
+    Named(Identifier),
+    /// `module.Name`.
+    Qualified(Qualified),
+    Generic(Generic),
+    /// `(T, ...)`. `(T)` is a one-element tuple too, only the `_comma` of its
+    /// element tells `(T,)` apart.
+    Tuple(Tuple),
+    Function(FunctionType),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Qualified {
+    pub qualifier: Qualifier,
+    pub identifier: Parsed<Identifier>,
+}
+
+/// Generic application like `Map[String, Int]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Generic {
+    pub identifier: Parsed<Identifier>,
+    pub _open_square: Parsed<WideToken>,
+    pub arguments: Vec<Parsed<TypeElement>>,
+    pub _close_square: Parsed<WideToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tuple {
+    pub _open_round: Parsed<WideToken>,
+    pub elements: Vec<Parsed<TypeElement>>,
+    pub _close_round: Parsed<WideToken>,
+}
+
+/// `function(Type, ...) [-> Type]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionType {
+    pub _introducer: Parsed<WideToken>,
+    pub _open_round: Parsed<WideToken>,
+    pub parameters: Vec<Parsed<TypeElement>>,
+    pub _close_round: Parsed<WideToken>,
+    pub return_type: Option<Box<ReturnType>>,
+}
+
+/// Type in a comma-separated list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Identifier;
+pub struct TypeElement {
+    pub type_: Parsed<TypeExpr>,
+    /// Missing only after the last type.
+    pub _comma: Option<Parsed<WideToken>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Identifier {
+    pub symbol: Symbol,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Statement {
     Let(Let),
+    Assignment(Assignment),
+    /// Expression evaluated only for its side effects.
+    Expression(Expression),
+    Return(Return),
+    If(If),
+    Match(Match),
+    While(While),
+    Loop(Loop),
+    For(For),
+    Break(Break),
+    Continue(Continue),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Let {
     pub _introducer: Parsed<WideToken>,
     pub identifier: Parsed<Identifier>,
+    pub annotation: Option<Box<Parsed<TypeAnnotation>>>,
     pub _equal: Parsed<WideToken>,
     pub value: Parsed<Expression>,
 }
 
+/// `target = value` or compound `target += value` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    pub target: Parsed<Expression>,
+    pub operator: Parsed<AssignmentOperator>,
+    pub value: Parsed<Expression>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AssignmentOperator {
+    /// `=`
+    Assign,
+    /// `+=`, `-=`, `*=` or `/=`, applying the operator to the old value.
+    Compound(BinaryOperator),
+}
+
+impl AssignmentOperator {
+    pub fn from_token(kind: &WideToken) -> Option<Self> {
+        match kind {
+            WideToken::Equal => Some(Self::Assign),
+            WideToken::PlusEqual => Some(Self::Compound(BinaryOperator::Add)),
+            WideToken::MinusEqual => {
+                Some(Self::Compound(BinaryOperator::Subtract))
+            }
+            WideToken::AsteriskEqual => {
+                Some(Self::Compound(BinaryOperator::Multiply))
+            }
+            WideToken::SlashEqual => {
+                Some(Self::Compound(BinaryOperator::Divide))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `return [value]` statement.
+///
+/// It is the only way to return a value: a bare expression at the end of a
+/// function body is not returned implicitly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Return {
+    pub _introducer: Parsed<WideToken>,
+    /// Present only if an expression starts right after the keyword.
+    pub value: Option<Parsed<Expression>>,
+}
+
+/// `if cond ... [elif cond ...] [else ...] end` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct If {
+    pub _introducer: Parsed<WideToken>,
+    pub condition: Parsed<Expression>,
+    pub body: Vec<Parsed<Statement>>,
+    pub elifs: Vec<Elif>,
+    pub else_: Option<Else>,
+    pub _end: Parsed<WideToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Elif {
+    pub _introducer: Parsed<WideToken>,
+    pub condition: Parsed<Expression>,
+    pub body: Vec<Parsed<Statement>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Else {
+    pub _introducer: Parsed<WideToken>,
+    pub body: Vec<Parsed<Statement>>,
+}
+
+/// `match value case Pattern ... end` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Match {
+    pub _introducer: Parsed<WideToken>,
+    pub scrutinee: Parsed<Expression>,
+    pub arms: Vec<MatchArm>,
+    pub _end: Parsed<WideToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchArm {
+    pub _introducer: Parsed<WideToken>,
+    pub pattern: Parsed<Pattern>,
+    pub body: Vec<Parsed<Statement>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Pattern {
+    /// `_`, matching anything.
+    Wildcard,
+    /// Integer, float or string literal, where numbers may be negated by a
+    /// unary `-`.
+    Literal(Expression),
+    /// Bare name.
+    ///
+    /// It is a variant without payload if such a variant is in scope,
+    /// otherwise it binds the value to a new variable. This is decided after
+    /// parsing.
+    Binding(Identifier),
+    Variant(VariantPattern),
+}
+
+/// `Name(Pattern, ...)`, destructuring a variant with payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantPattern {
+    pub identifier: Parsed<Identifier>,
+    pub _open_round: Parsed<WideToken>,
+    pub fields: Vec<Parsed<PatternElement>>,
+    pub _close_round: Parsed<WideToken>,
+}
+
+/// Pattern in a comma-separated list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternElement {
+    pub pattern: Parsed<Pattern>,
+    /// Missing only after the last pattern.
+    pub _comma: Option<Parsed<WideToken>>,
+}
+
+/// `@name` before a loop or after `break` and `continue`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    pub _at: Parsed<WideToken>,
+    pub identifier: Parsed<Identifier>,
+}
+
+/// `[@label] while cond ... end` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct While {
+    pub label: Option<Parsed<Label>>,
+    pub _introducer: Parsed<WideToken>,
+    pub condition: Parsed<Expression>,
+    pub body: Vec<Parsed<Statement>>,
+    pub _end: Parsed<WideToken>,
+}
+
+/// `[@label] loop ... end` statement, which repeats until `break`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Loop {
+    pub label: Option<Parsed<Label>>,
+    pub _introducer: Parsed<WideToken>,
+    pub body: Vec<Parsed<Statement>>,
+    pub _end: Parsed<WideToken>,
+}
+
+/// `[@label] for name in start..end ... end` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct For {
+    pub label: Option<Parsed<Label>>,
+    pub _introducer: Parsed<WideToken>,
+    pub variable: Parsed<Identifier>,
+    pub _in: Parsed<WideToken>,
+    pub start: Box<Parsed<Expression>>,
+    pub _double_dot: Parsed<WideToken>,
+    pub end: Box<Parsed<Expression>>,
+    pub body: Vec<Parsed<Statement>>,
+    pub _end: Parsed<WideToken>,
+}
+
+/// `break [@label]` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Break {
+    pub _introducer: Parsed<WideToken>,
+    pub label: Option<Parsed<Label>>,
+}
+
+/// `continue [@label]` statement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Continue {
+    pub _introducer: Parsed<WideToken>,
+    pub label: Option<Parsed<Label>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Expression {
-    Integer,
+    Integer(Integer),
+    Float(Float),
+    /// Contains the string with its escapes already resolved.
+    String(Symbol),
     Variable(Identifier),
+    Unary(Unary),
     Binary(Binary),
     Parenthesized(Parenthesized),
+    Call(Call),
+    Construction(Construction),
+    FieldAccess(FieldAccess),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Integer {
+    pub value: u128,
+    pub suffix: Option<IntegerSuffix>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Float {
+    pub value: f64,
+    pub suffix: Option<FloatSuffix>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Unary {
+    pub operator: Parsed<UnaryOperator>,
+    pub operand: Box<Parsed<Expression>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,32 +438,188 @@ pub struct Parenthesized {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Call {
+    pub callee: Box<Parsed<Expression>>,
+    pub _open_round: Parsed<WideToken>,
+    pub arguments: Vec<Parsed<Argument>>,
+    /// Error points at the opening bracket if the list is not closed.
+    pub _close_round: Parsed<WideToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Argument {
+    pub name: Option<ArgumentName>,
+    pub value: Parsed<Expression>,
+    /// Missing only after the last argument.
+    pub _comma: Option<Parsed<WideToken>>,
+}
+
+/// `name =` part of an argument.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArgumentName {
+    pub identifier: Parsed<Identifier>,
+    pub _equal: Parsed<WideToken>,
+}
+
+/// Record construction like `Point(x = 1, y = 2)`.
+///
+/// Parsed as a call whose arguments have names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Construction {
+    pub qualifier: Option<Box<Qualifier>>,
+    pub record: Parsed<Identifier>,
+    pub _open_round: Parsed<WideToken>,
+    /// Every field has a name.
+    pub fields: Vec<Parsed<Argument>>,
+    pub _close_round: Parsed<WideToken>,
+}
+
+/// `object.field`.
+///
+/// Also a qualified name `module.item`, if the object is a variable naming an
+/// imported module. They are told apart after parsing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldAccess {
+    pub object: Box<Parsed<Expression>>,
+    pub _dot: Parsed<WideToken>,
+    pub field: Parsed<Identifier>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BinaryOperator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
     Add,
     Subtract,
     Multiply,
     Divide,
+    Remainder,
+    Power,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+    /// Operators of the level can't follow each other, like in `a < b < c`.
+    None,
+}
+
+/// Levels of binary operators, from the loosest to the tightest.
+const PRECEDENCE_TABLE: &[(Associativity, &[BinaryOperator])] = {
+    use Associativity as A;
+    use BinaryOperator as B;
+    &[
+        (A::Left, &[B::Or]),
+        (A::Left, &[B::And]),
+        (
+            A::None,
+            &[
+                B::Equal,
+                B::NotEqual,
+                B::Less,
+                B::LessEqual,
+                B::Greater,
+                B::GreaterEqual,
+            ],
+        ),
+        (A::Left, &[B::Add, B::Subtract]),
+        (A::Left, &[B::Multiply, B::Divide, B::Remainder]),
+        (A::Right, &[B::Power]),
+    ]
+};
+
 impl BinaryOperator {
     pub fn from_token(kind: &WideToken) -> Option<Self> {
         match kind {
+            WideToken::OrKeyword => Some(Self::Or),
+            WideToken::AndKeyword => Some(Self::And),
+            WideToken::DoubleEqual => Some(Self::Equal),
+            WideToken::NotEqual => Some(Self::NotEqual),
+            WideToken::Less => Some(Self::Less),
+            WideToken::LessEqual => Some(Self::LessEqual),
+            WideToken::Greater => Some(Self::Greater),
+            WideToken::GreaterEqual => Some(Self::GreaterEqual),
             WideToken::Plus => Some(Self::Add),
             WideToken::Minus => Some(Self::Subtract),
             WideToken::Asterisk => Some(Self::Multiply),
             WideToken::Slash => Some(Self::Divide),
+            WideToken::Percent => Some(Self::Remainder),
+            WideToken::DoubleAsterisk => Some(Self::Power),
             _ => None,
         }
     }
 
+    /// Index of the operator's level in the table and its associativity.
+    fn level(&self) -> (usize, Associativity) {
+        PRECEDENCE_TABLE
+            .iter()
+            .enumerate()
+            .find(|(_, (_, operators))| operators.contains(self))
+            .map(|(index, (associativity, _))| (index, *associativity))
+            .expect("every operator is in the precedence table")
+    }
+
+    pub fn associativity(&self) -> Associativity {
+        self.level().1
+    }
+
+    /// Left and right binding powers.
     pub fn precedence(&self) -> (u8, u8) {
-        match self {
-            Self::Add | Self::Subtract => (1, 2),
-            Self::Multiply | Self::Divide => (3, 4),
+        let (index, associativity) = self.level();
+        let weaker = index as u8 * 2 + 1;
+        match associativity {
+            Associativity::Left | Associativity::None => (weaker, weaker + 1),
+            Associativity::Right => (weaker + 1, weaker),
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UnaryOperator {
+    /// `-`
+    Negate,
+    /// `+`
+    Identity,
+    /// `not`
+    Not,
+    /// `~`
+    BitwiseNot,
+}
+
+impl UnaryOperator {
+    pub fn from_token(kind: &WideToken) -> Option<Self> {
+        match kind {
+            WideToken::Minus => Some(Self::Negate),
+            WideToken::Plus => Some(Self::Identity),
+            WideToken::NotKeyword => Some(Self::Not),
+            WideToken::Tilde => Some(Self::BitwiseNot),
+            _ => None,
+        }
+    }
+
+    /// Minimal precedence of the operand.
+    ///
+    /// Arithmetic prefixes bind tighter than any binary operator except
+    /// `**`, so `-a ** b` is `-(a ** b)`. `not` takes a whole comparison:
+    /// `not a == b` is `not (a == b)`.
+    pub fn precedence(&self) -> u8 {
+        let operand_level = match self {
+            Self::Negate | Self::Identity | Self::BitwiseNot => {
+                BinaryOperator::Power
+            }
+            Self::Not => BinaryOperator::Equal,
+        };
+        operand_level.precedence().0
+    }
+}
+
 pub type Parsed<T> = Result<Spanned<T>, Spanned<NodeError>>;
 
 pub fn span_from_parsed<T>(parsed: &Parsed<T>) -> Span {
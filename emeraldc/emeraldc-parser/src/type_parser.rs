@@ -0,0 +1,172 @@
+use emeraldc_lexer::WideToken;
+use emeraldc_span::{IntoSpanned, Spanned};
+
+use crate::{
+    FatalParserError, FunctionType, Generic, Identifier, NodeError, Parsed,
+    Parser, Qualified, Qualifier, ReturnType, Subparser, Tuple, TypeElement,
+    TypeExpr, span_from_parsed,
+};
+
+pub struct TypeParser<'p> {
+    parser: &'p mut Parser,
+}
+
+impl<'p> Subparser<'p, TypeExpr> for TypeParser<'p> {
+    fn parse(
+        parser: &'p mut Parser,
+    ) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let this = Self::new(parser);
+        this.parse()
+    }
+}
+
+impl<'p> TypeParser<'p> {
+    fn new(parser: &'p mut Parser) -> Self {
+        Self { parser }
+    }
+
+    fn parse(mut self) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        match self.parser.tokens.peek().map(|t| &t.value) {
+            Some(WideToken::OpenRound) => self.parse_tuple(),
+            Some(WideToken::FunctionKeyword) => self.parse_function_type(),
+            Some(token) if token.had_error() => self.parse_named(),
+            Some(WideToken::Identifier(_)) => self.parse_named(),
+            Some(_) => self.missing_type(),
+            None => Err(FatalParserError::UnexpectedEof),
+        }
+    }
+
+    /// Reports the token without consuming it, so it may continue the
+    /// construct around the type, like `=` in `let x: = 1`.
+    fn missing_type(&mut self) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let token = self.parser.tokens.peek().unwrap();
+        let error = NodeError::UnexpectedToken(token.value.clone())
+            .into_spanned(token.span.clone());
+        Ok(Err(error))
+    }
+
+    /// Parses `Name` or a generic application `Name[Type, ...]`.
+    fn parse_named(&mut self) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let identifier = match self.parser.parse_identifier()? {
+            Ok(identifier) => identifier,
+            Err(error) => return Ok(Err(error)),
+        };
+        if self.parser.is_next(&WideToken::Dot) {
+            return self.parse_qualified(identifier);
+        }
+        if !self.parser.is_next(&WideToken::OpenSquare) {
+            let named = TypeExpr::Named(identifier.value);
+            return Ok(Ok(named.into_spanned(identifier.span)));
+        }
+        let _open_square = self.parser.expect(WideToken::OpenSquare)?;
+        let arguments = self.parse_elements(&WideToken::CloseSquare)?;
+        let _close_square = self.parser.expect_closing(
+            WideToken::CloseSquare,
+            &_open_square,
+            NodeError::UnclosedTypeList,
+        )?;
+        let span = identifier
+            .span
+            .clone()
+            .join(span_from_parsed(&_close_square));
+        let generic = Generic {
+            identifier: Ok(identifier),
+            _open_square,
+            arguments,
+            _close_square,
+        };
+        Ok(Ok(TypeExpr::Generic(generic).into_spanned(span)))
+    }
+
+    /// Parses the rest of `module.Name`.
+    fn parse_qualified(
+        &mut self,
+        module: Spanned<Identifier>,
+    ) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let _dot = self.parser.expect(WideToken::Dot)?;
+        let identifier = self.parser.parse_identifier()?;
+        let span = module.span.clone().join(span_from_parsed(&identifier));
+        let qualified = Qualified {
+            qualifier: Qualifier {
+                module: Ok(module),
+                _dot,
+            },
+            identifier,
+        };
+        Ok(Ok(TypeExpr::Qualified(qualified).into_spanned(span)))
+    }
+
+    fn parse_tuple(&mut self) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let _open_round = self.parser.expect(WideToken::OpenRound)?;
+        let elements = self.parse_elements(&WideToken::CloseRound)?;
+        let _close_round = self.parser.expect_closing(
+            WideToken::CloseRound,
+            &_open_round,
+            NodeError::UnclosedTypeList,
+        )?;
+        let span = span_from_parsed(&_open_round)
+            .join(span_from_parsed(&_close_round));
+        let tuple = Tuple {
+            _open_round,
+            elements,
+            _close_round,
+        };
+        Ok(Ok(TypeExpr::Tuple(tuple).into_spanned(span)))
+    }
+
+    /// Parses `function(Type, ...) [-> Type]`.
+    fn parse_function_type(
+        &mut self,
+    ) -> Result<Parsed<TypeExpr>, FatalParserError> {
+        let _introducer = self.parser.expect(WideToken::FunctionKeyword)?;
+        let _open_round = self.parser.expect(WideToken::OpenRound)?;
+        let parameters = self.parse_elements(&WideToken::CloseRound)?;
+        let _close_round = self.parser.expect_closing(
+            WideToken::CloseRound,
+            &_open_round,
+            NodeError::UnclosedTypeList,
+        )?;
+        let mut span = span_from_parsed(&_introducer)
+            .join(span_from_parsed(&_close_round));
+        let return_type = if self.parser.is_next(&WideToken::Arrow) {
+            let _arrow = self.parser.expect(WideToken::Arrow)?;
+            let type_ = self.parser.parse_type()?;
+            span = span.join(span_from_parsed(&type_));
+            Some(Box::new(ReturnType { _arrow, type_ }))
+        } else {
+            None
+        };
+        let function = FunctionType {
+            _introducer,
+            _open_round,
+            parameters,
+            _close_round,
+            return_type,
+        };
+        Ok(Ok(TypeExpr::Function(function).into_spanned(span)))
+    }
+
+    /// Parses comma-separated types up to the closing bracket.
+    ///
+    /// The bracket itself is left to the caller.
+    fn parse_elements(
+        &mut self,
+        close: &WideToken,
+    ) -> Result<Vec<Parsed<TypeElement>>, FatalParserError> {
+        self.parser.parse_comma_separated(
+            close,
+            Self::is_type_start,
+            Parser::parse_type,
+            |type_, _comma| TypeElement { type_, _comma },
+        )
+    }
+
+    fn is_type_start(token: &WideToken) -> bool {
+        matches!(
+            token,
+            WideToken::Identifier(_)
+                | WideToken::OpenRound
+                | WideToken::FunctionKeyword
+        )
+    }
+}
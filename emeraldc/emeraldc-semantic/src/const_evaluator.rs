@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::{FloatSuffix, IntegerSuffix};
+use emeraldc_parser::{
+    Binary, BinaryOperator, Const, Declaration, Expression, Parsed, TypeExpr,
+    Unary, UnaryOperator,
+};
+use emeraldc_span::{IntoSpanned, Span, Spanned};
+use emeraldc_tree_checker::{CheckError, Report};
+
+use crate::{ConstInteger, ItemTable, Module, Visibility};
+
+/// Value of a constant, known at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ConstValue {
+    Integer(ConstInteger),
+    Float(f64),
+    String(Symbol),
+    Bool(bool),
+}
+
+/// Constant is named by the index of its module and its name.
+type ConstKey = (usize, Symbol);
+
+/// Evaluates every constant of the program once, in any order it is
+/// referred to.
+///
+/// Failed evaluations yield no value and are reported once, where they fail,
+/// so constants that depend on them are not reported again.
+pub(crate) struct ConstEvaluator<'m, 'r> {
+    modules: &'m [Module],
+    items: &'m [ItemTable],
+    /// Declarations in the order they are written.
+    ///
+    /// If several constants of a module have the same name, the first one
+    /// is kept.
+    constants: Vec<(ConstKey, &'m Const)>,
+    values: HashMap<ConstKey, Option<ConstValue>>,
+    /// Constants being evaluated, to find those that depend on themselves.
+    in_progress: Vec<ConstKey>,
+    pool: &'r mut Vec<Report>,
+}
+
+impl<'m, 'r> ConstEvaluator<'m, 'r> {
+    pub(crate) fn evaluate(
+        modules: &'m [Module],
+        items: &'m [ItemTable],
+        pool: &'r mut Vec<Report>,
+    ) {
+        let mut constants: Vec<(ConstKey, &Const)> = Vec::new();
+        for (index, module) in modules.iter().enumerate() {
+            for declaration in module.declarations() {
+                let Declaration::Const(const_) = &declaration.value else {
+                    continue;
+                };
+                let Ok(identifier) = &const_.identifier else {
+                    continue;
+                };
+                let key = (index, identifier.value.symbol);
+                if !constants.iter().any(|(seen, _)| *seen == key) {
+                    constants.push((key, const_));
+                }
+            }
+        }
+        let mut this = Self {
+            modules,
+            items,
+            constants,
+            values: HashMap::new(),
+            in_progress: Vec::new(),
+            pool,
+        };
+        for index in 0..this.constants.len() {
+            let (key, const_) = this.constants[index];
+            let span = match &const_.identifier {
+                Ok(identifier) => identifier.span.clone(),
+                Err(_) => unreachable!("unnamed constants are skipped"),
+            };
+            this.constant_value(key, &span);
+        }
+    }
+
+    /// Evaluates the constant, unless it is already evaluated.
+    ///
+    /// A cycle is reported at the reference that closes it.
+    fn constant_value(
+        &mut self,
+        key: ConstKey,
+        reference: &Span,
+    ) -> Option<ConstValue> {
+        if let Some(value) = self.values.get(&key) {
+            return value.clone();
+        }
+        if self.in_progress.contains(&key) {
+            self.report(CheckError::ConstantCycle(key.1), reference);
+            return None;
+        }
+        let (_, const_) = *self.constants.iter().find(|(k, _)| *k == key)?;
+        self.in_progress.push(key);
+        let value = self.evaluate_expression(key.0, &const_.value);
+        self.in_progress.pop();
+        if let Some(value) = &value
+            && let Ok(annotation) = &*const_.annotation
+            && let Ok(value_node) = &const_.value
+        {
+            self.check_type(value, &annotation.value.type_, &value_node.span);
+        }
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// Checks the value against suffix types like `u8` and `f64`.
+    ///
+    /// Other types can't be checked until there is a type checker.
+    fn check_type(
+        &mut self,
+        value: &ConstValue,
+        type_: &Parsed<TypeExpr>,
+        span: &Span,
+    ) {
+        let Ok(Spanned {
+            value: TypeExpr::Named(identifier),
+            ..
+        }) = type_
+        else {
+            return;
+        };
+        let name = identifier.symbol.as_str();
+        let fits = if let Some(suffix) = IntegerSuffix::from_lexeme(name) {
+            match value {
+                ConstValue::Integer(integer) => {
+                    integer.fits(suffix.min_value(), suffix.max_value())
+                }
+                _ => false,
+            }
+        } else if FloatSuffix::from_lexeme(name).is_some() {
+            matches!(value, ConstValue::Float(_))
+        } else {
+            true
+        };
+        if !fits {
+            let error = CheckError::ConstantTypeMismatch(identifier.symbol);
+            self.report(error, span);
+        }
+    }
+
+    fn evaluate_expression(
+        &mut self,
+        module: usize,
+        expression: &Parsed<Expression>,
+    ) -> Option<ConstValue> {
+        let expression = expression.as_ref().ok()?;
+        let span = &expression.span;
+        match &expression.value {
+            Expression::Integer(integer) => {
+                Some(ConstValue::Integer(integer.value.into()))
+            }
+            Expression::Float(float) => Some(ConstValue::Float(float.value)),
+            Expression::String(symbol) => Some(ConstValue::String(*symbol)),
+            Expression::Variable(identifier) => {
+                let name = identifier.symbol;
+                if self.is_constant((module, name)) {
+                    return self.constant_value((module, name), span);
+                }
+                let error = match self.items[module].visibility(name) {
+                    Some(_) => CheckError::NonConstantExpression,
+                    None => CheckError::UnknownConstant(name),
+                };
+                self.fail(error, span)
+            }
+            Expression::Unary(unary) => {
+                self.evaluate_unary(module, unary, span)
+            }
+            Expression::Binary(binary) => {
+                self.evaluate_binary(module, binary, span)
+            }
+            Expression::Parenthesized(parenthesized) => {
+                self.evaluate_expression(module, &parenthesized.inner)
+            }
+            Expression::Call(_) | Expression::Construction(_) => {
+                self.fail(CheckError::NonConstantExpression, span)
+            }
+            Expression::FieldAccess(access) => {
+                let Ok(Spanned {
+                    value: Expression::Variable(qualifier),
+                    ..
+                }) = &*access.object
+                else {
+                    return self.fail(CheckError::NonConstantExpression, span);
+                };
+                let Some(imported) =
+                    self.modules[module].import(qualifier.symbol)
+                else {
+                    return self.fail(CheckError::NonConstantExpression, span);
+                };
+                let imported = imported?;
+                let name = access.field.as_ref().ok()?.value.symbol;
+                // unknown and private items are reported with other
+                // qualified names
+                match self.items[imported].visibility(name)? {
+                    Visibility::Public
+                        if self.is_constant((imported, name)) =>
+                    {
+                        self.constant_value((imported, name), span)
+                    }
+                    Visibility::Public => {
+                        self.fail(CheckError::NonConstantExpression, span)
+                    }
+                    Visibility::Private => None,
+                }
+            }
+        }
+    }
+
+    fn evaluate_unary(
+        &mut self,
+        module: usize,
+        unary: &Unary,
+        span: &Span,
+    ) -> Option<ConstValue> {
+        let operator = &unary.operator.as_ref().ok()?.value;
+        let operand = self.evaluate_expression(module, &unary.operand)?;
+        let value = match (operator, operand) {
+            (UnaryOperator::Negate, ConstValue::Integer(integer)) => {
+                ConstValue::Integer(-integer)
+            }
+            (UnaryOperator::Negate, ConstValue::Float(float)) => {
+                ConstValue::Float(-float)
+            }
+            (
+                UnaryOperator::Identity,
+                value @ (ConstValue::Integer(_) | ConstValue::Float(_)),
+            ) => value,
+            (UnaryOperator::Not, ConstValue::Bool(bool)) => {
+                ConstValue::Bool(!bool)
+            }
+            (UnaryOperator::BitwiseNot, ConstValue::Integer(integer)) => {
+                match integer.checked_not() {
+                    Some(integer) => ConstValue::Integer(integer),
+                    None => {
+                        return self.fail(CheckError::ConstantOverflow, span);
+                    }
+                }
+            }
+            _ => return self.fail(CheckError::InvalidConstantOperation, span),
+        };
+        Some(value)
+    }
+
+    /// `and` and `or` don't evaluate the right operand if the left one
+    /// decides the result.
+    fn evaluate_binary(
+        &mut self,
+        module: usize,
+        binary: &Binary,
+        span: &Span,
+    ) -> Option<ConstValue> {
+        let operator = binary.operator.as_ref().ok()?.value;
+        let left = self.evaluate_expression(module, &binary.left)?;
+        match (operator, &left) {
+            (BinaryOperator::And, ConstValue::Bool(false))
+            | (BinaryOperator::Or, ConstValue::Bool(true)) => {
+                return Some(left);
+            }
+            _ => {}
+        }
+        let right = self.evaluate_expression(module, &binary.right)?;
+        match Self::apply(operator, left, right) {
+            Ok(value) => Some(value),
+            Err(error) => self.fail(error, span),
+        }
+    }
+
+    fn apply(
+        operator: BinaryOperator,
+        left: ConstValue,
+        right: ConstValue,
+    ) -> Result<ConstValue, CheckError> {
+        use ConstValue::{Bool, Float, Integer, String};
+        let overflow = CheckError::ConstantOverflow;
+        let value = match (operator, left, right) {
+            (
+                BinaryOperator::And | BinaryOperator::Or,
+                Bool(_),
+                Bool(right),
+            ) => Bool(right),
+            (BinaryOperator::Equal, left, right)
+                if Self::same_kind(&left, &right) =>
+            {
+                Bool(left == right)
+            }
+            (BinaryOperator::NotEqual, left, right)
+                if Self::same_kind(&left, &right) =>
+            {
+                Bool(left != right)
+            }
+            (
+                BinaryOperator::Less
+                | BinaryOperator::LessEqual
+                | BinaryOperator::Greater
+                | BinaryOperator::GreaterEqual,
+                left,
+                right,
+            ) => {
+                let ordering = match (left, right) {
+                    (Integer(left), Integer(right)) => left.partial_cmp(&right),
+                    (Float(left), Float(right)) => left.partial_cmp(&right),
+                    (String(left), String(right)) => {
+                        left.as_str().partial_cmp(right.as_str())
+                    }
+                    _ => return Err(CheckError::InvalidConstantOperation),
+                };
+                // comparisons with NaN are false
+                let Some(ordering) = ordering else {
+                    return Ok(Bool(false));
+                };
+                Bool(match operator {
+                    BinaryOperator::Less => ordering.is_lt(),
+                    BinaryOperator::LessEqual => ordering.is_le(),
+                    BinaryOperator::Greater => ordering.is_gt(),
+                    _ => ordering.is_ge(),
+                })
+            }
+            (BinaryOperator::Add, String(left), String(right)) => {
+                let concatenated = [left.as_str(), right.as_str()].concat();
+                String(Symbol::intern(&concatenated))
+            }
+            (operator, Integer(left), Integer(right)) => {
+                let result = match operator {
+                    BinaryOperator::Add => left.checked_add(right),
+                    BinaryOperator::Subtract => left.checked_sub(right),
+                    BinaryOperator::Multiply => left.checked_mul(right),
+                    BinaryOperator::Divide | BinaryOperator::Remainder
+                        if right.is_zero() =>
+                    {
+                        return Err(CheckError::DivisionByZero);
+                    }
+                    BinaryOperator::Divide => left.checked_div(right),
+                    BinaryOperator::Remainder => left.checked_rem(right),
+                    BinaryOperator::Power if right.is_negative() => {
+                        return Err(CheckError::InvalidConstantOperation);
+                    }
+                    BinaryOperator::Power => right
+                        .to_unsigned()
+                        .and_then(|right| u32::try_from(right).ok())
+                        .and_then(|right| left.checked_pow(right)),
+                    _ => return Err(CheckError::InvalidConstantOperation),
+                };
+                Integer(result.ok_or(overflow)?)
+            }
+            (operator, Float(left), Float(right)) => Float(match operator {
+                BinaryOperator::Add => left + right,
+                BinaryOperator::Subtract => left - right,
+                BinaryOperator::Multiply => left * right,
+                BinaryOperator::Divide => left / right,
+                BinaryOperator::Remainder => left % right,
+                BinaryOperator::Power => left.powf(right),
+                _ => return Err(CheckError::InvalidConstantOperation),
+            }),
+            _ => return Err(CheckError::InvalidConstantOperation),
+        };
+        Ok(value)
+    }
+
+    /// Values of different kinds are never converted into each other.
+    fn same_kind(left: &ConstValue, right: &ConstValue) -> bool {
+        std::mem::discriminant(left) == std::mem::discriminant(right)
+    }
+
+    fn is_constant(&self, key: ConstKey) -> bool {
+        self.constants.iter().any(|(k, _)| *k == key)
+    }
+
+    fn fail(&mut self, error: CheckError, span: &Span) -> Option<ConstValue> {
+        self.report(error, span);
+        None
+    }
+
+    fn report(&mut self, error: CheckError, span: &Span) {
+        self.pool
+            .push(Report::Check(error.into_spanned(span.clone())));
+    }
+}
@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+
+/// Integer value of a constant, wide enough for every integer type, from
+/// `i128::MIN` to `u128::MAX`.
+///
+/// Kept as a sign and a magnitude, with zero never negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ConstInteger {
+    negative: bool,
+    magnitude: u128,
+}
+
+impl ConstInteger {
+    pub(crate) fn new(negative: bool, magnitude: u128) -> Self {
+        Self {
+            negative: negative && magnitude != 0,
+            magnitude,
+        }
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self.magnitude == 0
+    }
+
+    pub(crate) fn is_negative(self) -> bool {
+        self.negative
+    }
+
+    /// Whether the integer is in `min..=max`.
+    pub(crate) fn fits(self, min: i128, max: u128) -> bool {
+        if self.negative {
+            min < 0 && self.magnitude <= min.unsigned_abs()
+        } else {
+            self.magnitude <= max
+        }
+    }
+
+    pub(crate) fn checked_add(self, other: Self) -> Option<Self> {
+        if self.negative == other.negative {
+            let magnitude = self.magnitude.checked_add(other.magnitude)?;
+            Some(Self::new(self.negative, magnitude))
+        } else if self.magnitude >= other.magnitude {
+            Some(Self::new(self.negative, self.magnitude - other.magnitude))
+        } else {
+            Some(Self::new(other.negative, other.magnitude - self.magnitude))
+        }
+    }
+
+    pub(crate) fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    pub(crate) fn checked_mul(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_mul(other.magnitude)?;
+        Some(Self::new(self.negative != other.negative, magnitude))
+    }
+
+    /// Rounds toward zero, like integer division of Rust.
+    pub(crate) fn checked_div(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_div(other.magnitude)?;
+        Some(Self::new(self.negative != other.negative, magnitude))
+    }
+
+    /// Remainder has the sign of the dividend.
+    pub(crate) fn checked_rem(self, other: Self) -> Option<Self> {
+        let magnitude = self.magnitude.checked_rem(other.magnitude)?;
+        Some(Self::new(self.negative, magnitude))
+    }
+
+    pub(crate) fn checked_pow(self, exponent: u32) -> Option<Self> {
+        let magnitude = self.magnitude.checked_pow(exponent)?;
+        Some(Self::new(self.negative && exponent % 2 == 1, magnitude))
+    }
+
+    /// Bitwise negation in two's complement, that is `-self - 1`.
+    pub(crate) fn checked_not(self) -> Option<Self> {
+        if self.negative {
+            Some(Self::new(false, self.magnitude - 1))
+        } else {
+            Some(Self::new(true, self.magnitude.checked_add(1)?))
+        }
+    }
+
+    /// Magnitude, if the integer is not negative.
+    pub(crate) fn to_unsigned(self) -> Option<u128> {
+        (!self.negative).then_some(self.magnitude)
+    }
+}
+
+impl From<u128> for ConstInteger {
+    fn from(magnitude: u128) -> Self {
+        Self::new(false, magnitude)
+    }
+}
+
+impl std::ops::Neg for ConstInteger {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Ord for ConstInteger {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for ConstInteger {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
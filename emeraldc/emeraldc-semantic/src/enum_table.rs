@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{Enum, TypeExpr, Variant};
+
+/// Variants of every enum visible in a module.
+pub(crate) struct EnumTable {
+    enums: HashMap<Symbol, Vec<VariantInfo>>,
+    /// Enums and indices of every variant name.
+    ///
+    /// Patterns name variants without their enum, so a name declared by
+    /// several enums is ambiguous, unless one of the module's own enums
+    /// declares it and hides the imported ones.
+    variants: HashMap<Symbol, Vec<VariantPlace>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct VariantPlace {
+    enum_: Symbol,
+    index: usize,
+    imported: bool,
+}
+
+/// Meaning of a variant name in a pattern.
+#[derive(Debug, Clone)]
+pub(crate) enum VariantLookup {
+    Unknown,
+    /// Enum and index of the variant.
+    Found(Symbol, usize),
+    /// Enums that declare a variant with this name.
+    Ambiguous(Vec<Symbol>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct VariantInfo {
+    pub(crate) name: Symbol,
+    /// Number of payload fields.
+    pub(crate) arity: usize,
+}
+
+impl EnumTable {
+    pub(crate) fn new() -> Self {
+        Self {
+            enums: HashMap::new(),
+            variants: HashMap::new(),
+        }
+    }
+
+    /// Adds the enum, unless another enum with the same name was added
+    /// before.
+    ///
+    /// Variants that failed to parse are left out, and so is every repetition
+    /// of a variant, because their errors are already reported. Enums of the
+    /// module itself must be added before the imported ones.
+    pub(crate) fn declare(&mut self, enum_: &Enum, imported: bool) {
+        let Ok(identifier) = &enum_.identifier else {
+            return;
+        };
+        let Entry::Vacant(entry) = self.enums.entry(identifier.value.symbol)
+        else {
+            return;
+        };
+        let mut variants: Vec<VariantInfo> = Vec::new();
+        for variant in enum_.variants.iter().flatten() {
+            let Some(info) = Self::variant_info(&variant.value) else {
+                continue;
+            };
+            if variants.iter().any(|seen| seen.name == info.name) {
+                continue;
+            }
+            let places = self.variants.entry(info.name).or_default();
+            let hidden = imported && places.iter().any(|place| !place.imported);
+            if !hidden {
+                places.push(VariantPlace {
+                    enum_: identifier.value.symbol,
+                    index: variants.len(),
+                    imported,
+                });
+            }
+            variants.push(info);
+        }
+        entry.insert(variants);
+    }
+
+    fn variant_info(variant: &Variant) -> Option<VariantInfo> {
+        let name = variant.identifier.as_ref().ok()?.value.symbol;
+        let arity = match &variant.payload {
+            None => 0,
+            Some(Ok(payload)) => match &payload.value {
+                TypeExpr::Tuple(tuple) => tuple.elements.len(),
+                _ => 1,
+            },
+            Some(Err(_)) => return None,
+        };
+        Some(VariantInfo { name, arity })
+    }
+
+    pub(crate) fn lookup(&self, name: Symbol) -> VariantLookup {
+        match self.variants.get(&name).map(Vec::as_slice) {
+            None | Some([]) => VariantLookup::Unknown,
+            Some([place]) => VariantLookup::Found(place.enum_, place.index),
+            Some(places) => VariantLookup::Ambiguous(
+                places.iter().map(|place| place.enum_).collect(),
+            ),
+        }
+    }
+
+    pub(crate) fn variants(&self, enum_: Symbol) -> &[VariantInfo] {
+        &self.enums[&enum_]
+    }
+
+    pub(crate) fn variant(&self, enum_: Symbol, index: usize) -> VariantInfo {
+        self.enums[&enum_][index]
+    }
+}
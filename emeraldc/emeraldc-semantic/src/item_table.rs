@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use emeraldc_interner::Symbol;
+use emeraldc_parser::Declaration;
+
+use crate::Module;
+
+/// Items a module declares, that other modules can refer to.
+pub(crate) struct ItemTable {
+    items: HashMap<Symbol, Visibility>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Visibility {
+    Public,
+    /// Seen only inside of its module.
+    Private,
+}
+
+impl ItemTable {
+    /// If several items have the same name, the first one is kept.
+    pub(crate) fn collect(module: &Module) -> Self {
+        let mut items = HashMap::new();
+        for declaration in module.declarations() {
+            let (identifier, public) = match &declaration.value {
+                Declaration::Function(function) => {
+                    (&function.identifier, &function.public)
+                }
+                Declaration::Record(record) => {
+                    (&record.identifier, &record.public)
+                }
+                Declaration::Enum(enum_) => (&enum_.identifier, &enum_.public),
+                Declaration::Const(const_) => {
+                    (&const_.identifier, &const_.public)
+                }
+                Declaration::Import(_) => continue,
+            };
+            let Ok(identifier) = identifier else {
+                continue;
+            };
+            items
+                .entry(identifier.value.symbol)
+                .or_insert_with(|| Visibility::of(public));
+        }
+        Self { items }
+    }
+
+    pub(crate) fn visibility(&self, name: Symbol) -> Option<Visibility> {
+        self.items.get(&name).copied()
+    }
+}
+
+impl Visibility {
+    pub(crate) fn of<T>(public: &Option<T>) -> Self {
+        match public {
+            Some(_) => Self::Public,
+            None => Self::Private,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+mod const_evaluator;
+mod const_integer;
+mod enum_table;
+mod item_table;
+mod match_checker;
+mod module;
+mod pattern;
+mod qualified_name_checker;
+mod semantic_analyzer;
+mod usefulness;
+use const_evaluator::*;
+use const_integer::*;
+use enum_table::*;
+use item_table::*;
+use match_checker::*;
+pub use module::*;
+use pattern::*;
+use qualified_name_checker::*;
+pub use semantic_analyzer::*;
+use usefulness::*;
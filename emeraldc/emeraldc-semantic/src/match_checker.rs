@@ -0,0 +1,101 @@
+use emeraldc_parser::{Match, Parsed, Statement};
+use emeraldc_span::{IntoSpanned, Span};
+use emeraldc_tree_checker::{CheckError, Report, Warning};
+
+use crate::{DeconstructedPattern, EnumTable, Usefulness};
+
+/// Checks that every `match` is exhaustive and every `case` is reachable.
+pub(crate) struct MatchChecker<'t, 'r> {
+    table: &'t EnumTable,
+    pool: &'r mut Vec<Report>,
+}
+
+impl<'t, 'r> MatchChecker<'t, 'r> {
+    pub(crate) fn check(
+        body: &[Parsed<Statement>],
+        table: &'t EnumTable,
+        pool: &'r mut Vec<Report>,
+    ) {
+        let mut this = Self { table, pool };
+        this.check_block(body);
+    }
+
+    fn check_block(&mut self, body: &[Parsed<Statement>]) {
+        for statement in body.iter().flatten() {
+            self.check_statement(&statement.value, &statement.span);
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Statement, span: &Span) {
+        match statement {
+            Statement::Let(_)
+            | Statement::Assignment(_)
+            | Statement::Expression(_)
+            | Statement::Return(_)
+            | Statement::Break(_)
+            | Statement::Continue(_) => {}
+            Statement::If(if_) => {
+                self.check_block(&if_.body);
+                for elif in &if_.elifs {
+                    self.check_block(&elif.body);
+                }
+                if let Some(else_) = &if_.else_ {
+                    self.check_block(&else_.body);
+                }
+            }
+            Statement::Match(match_) => {
+                self.check_match(match_, span);
+                for arm in &match_.arms {
+                    self.check_block(&arm.body);
+                }
+            }
+            Statement::While(while_) => self.check_block(&while_.body),
+            Statement::Loop(loop_) => self.check_block(&loop_.body),
+            Statement::For(for_) => self.check_block(&for_.body),
+        }
+    }
+
+    /// Exhaustiveness is not checked if some pattern is invalid, because
+    /// the values it was meant to cover would be reported as missing.
+    fn check_match(&mut self, match_: &Match, span: &Span) {
+        let usefulness = Usefulness::new(self.table);
+        let mut rows = Vec::new();
+        let mut all_valid = true;
+        for arm in &match_.arms {
+            let pattern = DeconstructedPattern::lower(
+                &arm.pattern,
+                self.table,
+                self.pool,
+            );
+            let Some(pattern) = pattern else {
+                all_valid = false;
+                continue;
+            };
+            let row = vec![pattern];
+            if !usefulness.is_useful(&rows, &row)
+                && let Ok(pattern) = &arm.pattern
+            {
+                let warning =
+                    Warning::UnreachableCase.into_spanned(pattern.span.clone());
+                self.pool.push(Report::Warning(warning));
+            }
+            rows.push(row);
+        }
+        if !all_valid {
+            return;
+        }
+        let missing = usefulness
+            .missing(&rows, 1)
+            .into_iter()
+            .map(|witnesses| witnesses[0].to_string())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            let span = match &match_._introducer {
+                Ok(introducer) => introducer.span.clone(),
+                Err(_) => span.clone(),
+            };
+            let error = CheckError::NonExhaustiveMatch(missing);
+            self.pool.push(Report::Check(error.into_spanned(span)));
+        }
+    }
+}
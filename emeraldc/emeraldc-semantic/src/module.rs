@@ -0,0 +1,29 @@
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{Declaration, FatalParserError, Parsed};
+use emeraldc_span::Spanned;
+
+/// File of the program, parsed.
+#[derive(Debug)]
+pub struct Module {
+    /// Dotted path of the module under the source root.
+    pub name: String,
+    pub tree: Vec<Result<Parsed<Declaration>, FatalParserError>>,
+    /// Last segment of every imported path with the index of its module,
+    /// `None` if the module failed to load, in the order of the imports.
+    pub imports: Vec<(Symbol, Option<usize>)>,
+}
+
+impl Module {
+    /// Declarations that were parsed.
+    pub fn declarations(&self) -> impl Iterator<Item = &Spanned<Declaration>> {
+        self.tree.iter().flatten().flatten()
+    }
+
+    /// Module imported under this name, `Some(None)` if it failed to load.
+    pub fn import(&self, name: Symbol) -> Option<Option<usize>> {
+        self.imports
+            .iter()
+            .find(|(imported, _)| *imported == name)
+            .map(|(_, index)| *index)
+    }
+}
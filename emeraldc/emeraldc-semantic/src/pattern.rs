@@ -0,0 +1,181 @@
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{Expression, Parsed, Pattern, UnaryOperator};
+use emeraldc_span::{IntoSpanned, Span};
+use emeraldc_tree_checker::{CheckError, Report};
+
+use crate::{ConstInteger, EnumTable, VariantLookup};
+
+/// Pattern reduced to what matters for exhaustiveness.
+///
+/// Bindings become wildcards, and bare names of variants become constructors,
+/// which requires the variant to have no payload.
+#[derive(Debug, Clone)]
+pub(crate) enum DeconstructedPattern {
+    Wildcard,
+    Constructor(Constructor, Vec<DeconstructedPattern>),
+}
+
+/// Shape of a value that a pattern tests for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Constructor {
+    Variant { enum_: Symbol, index: usize },
+    Literal(Literal),
+}
+
+/// Literal compared by value, so `0x10` and `16` are the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Literal {
+    Integer(ConstInteger),
+    /// Bits of the float.
+    Float(u64),
+    String(Symbol),
+}
+
+impl DeconstructedPattern {
+    /// Resolves variant names of the pattern.
+    ///
+    /// Returns `None` if the pattern failed to parse or names variants
+    /// wrongly, reporting the latter.
+    pub(crate) fn lower(
+        pattern: &Parsed<Pattern>,
+        table: &EnumTable,
+        pool: &mut Vec<Report>,
+    ) -> Option<Self> {
+        let pattern = pattern.as_ref().ok()?;
+        match &pattern.value {
+            Pattern::Wildcard => Some(Self::Wildcard),
+            Pattern::Literal(literal) => {
+                let literal = Literal::from_expression(literal)?;
+                Some(Self::Constructor(Constructor::Literal(literal), vec![]))
+            }
+            Pattern::Binding(identifier) => {
+                match table.lookup(identifier.symbol) {
+                    VariantLookup::Found(enum_, index) => {
+                        let expected = table.variant(enum_, index).arity;
+                        if expected > 0 {
+                            let error = CheckError::VariantArity {
+                                variant: identifier.symbol,
+                                expected,
+                                found: 0,
+                            };
+                            Self::report(error, &pattern.span, pool);
+                            return None;
+                        }
+                        let variant = Constructor::Variant { enum_, index };
+                        Some(Self::Constructor(variant, vec![]))
+                    }
+                    VariantLookup::Ambiguous(enums) => {
+                        let error = CheckError::AmbiguousVariant {
+                            variant: identifier.symbol,
+                            enums,
+                        };
+                        Self::report(error, &pattern.span, pool);
+                        None
+                    }
+                    VariantLookup::Unknown => Some(Self::Wildcard),
+                }
+            }
+            Pattern::Variant(variant) => {
+                let identifier = variant.identifier.as_ref().ok()?;
+                let name = identifier.value.symbol;
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let field = field.as_ref().ok()?;
+                        Self::lower(&field.value.pattern, table, pool)
+                    })
+                    .collect::<Vec<_>>();
+                let (enum_, index) = match table.lookup(name) {
+                    VariantLookup::Found(enum_, index) => (enum_, index),
+                    VariantLookup::Unknown => {
+                        let error = CheckError::UnknownVariant(name);
+                        Self::report(error, &identifier.span, pool);
+                        return None;
+                    }
+                    VariantLookup::Ambiguous(enums) => {
+                        let error = CheckError::AmbiguousVariant {
+                            variant: name,
+                            enums,
+                        };
+                        Self::report(error, &identifier.span, pool);
+                        return None;
+                    }
+                };
+                let expected = table.variant(enum_, index).arity;
+                if fields.len() != expected {
+                    let error = CheckError::VariantArity {
+                        variant: name,
+                        expected,
+                        found: fields.len(),
+                    };
+                    Self::report(error, &pattern.span, pool);
+                    return None;
+                }
+                let fields = fields.into_iter().collect::<Option<Vec<_>>>()?;
+                let variant = Constructor::Variant { enum_, index };
+                Some(Self::Constructor(variant, fields))
+            }
+        }
+    }
+
+    fn report(error: CheckError, span: &Span, pool: &mut Vec<Report>) {
+        pool.push(Report::Check(error.into_spanned(span.clone())));
+    }
+}
+
+impl Literal {
+    fn from_expression(expression: &Expression) -> Option<Self> {
+        match expression {
+            Expression::Integer(integer) => {
+                Some(Self::Integer(integer.value.into()))
+            }
+            Expression::Float(float) => {
+                Some(Self::Float(float.value.to_bits()))
+            }
+            Expression::String(symbol) => Some(Self::String(*symbol)),
+            Expression::Unary(unary) => {
+                let operator = &unary.operator.as_ref().ok()?.value;
+                let operand = &unary.operand.as_ref().as_ref().ok()?.value;
+                match (operator, Self::from_expression(operand)?) {
+                    (UnaryOperator::Negate, Self::Integer(integer)) => {
+                        Some(Self::Integer(-integer))
+                    }
+                    (UnaryOperator::Negate, Self::Float(bits)) => {
+                        Some(Self::Float((-f64::from_bits(bits)).to_bits()))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Value that none of the patterns match, written as a pattern.
+#[derive(Debug, Clone)]
+pub(crate) enum Witness {
+    Wildcard,
+    Variant(Symbol, Vec<Witness>),
+}
+
+impl std::fmt::Display for Witness {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Wildcard => write!(f, "_"),
+            Self::Variant(name, fields) if fields.is_empty() => {
+                write!(f, "{name}")
+            }
+            Self::Variant(name, fields) => {
+                write!(f, "{name}(")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{field}")?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{
+    Declaration, Expression, Identifier, Parsed, Qualifier, Statement, TypeExpr,
+};
+use emeraldc_span::{IntoSpanned, Spanned};
+use emeraldc_tree_checker::{CheckError, Report};
+
+use crate::{ItemTable, Module, Visibility};
+
+/// Checks that every `module.item` names a public item of an imported module.
+///
+/// In expressions, `name.field` is a qualified name only when `name` is an
+/// imported module, and a field access otherwise.
+pub(crate) struct QualifiedNameChecker<'m, 'r> {
+    module: &'m Module,
+    /// Items of every module of the program, by module index.
+    items: &'m [ItemTable],
+    pool: &'r mut Vec<Report>,
+}
+
+impl<'m, 'r> QualifiedNameChecker<'m, 'r> {
+    pub(crate) fn check(
+        declaration: &Declaration,
+        module: &'m Module,
+        items: &'m [ItemTable],
+        pool: &'r mut Vec<Report>,
+    ) {
+        let mut this = Self {
+            module,
+            items,
+            pool,
+        };
+        this.check_declaration(declaration);
+    }
+
+    fn check_declaration(&mut self, declaration: &Declaration) {
+        match declaration {
+            Declaration::Function(function) => {
+                for parameter in function.parameters.iter().flatten() {
+                    if let Ok(annotation) = &parameter.value.annotation {
+                        self.check_type(&annotation.value.type_);
+                    }
+                }
+                if let Some(return_type) = &function.return_type {
+                    self.check_type(&return_type.type_);
+                }
+                self.check_block(&function.body);
+            }
+            Declaration::Record(record) => {
+                for field in record.fields.iter().flatten() {
+                    if let Ok(annotation) = &field.value.annotation {
+                        self.check_type(&annotation.value.type_);
+                    }
+                }
+            }
+            Declaration::Enum(enum_) => {
+                for variant in enum_.variants.iter().flatten() {
+                    if let Some(payload) = &variant.value.payload {
+                        self.check_type(payload);
+                    }
+                }
+            }
+            Declaration::Const(const_) => {
+                if let Ok(annotation) = &*const_.annotation {
+                    self.check_type(&annotation.value.type_);
+                }
+                self.check_expression(&const_.value);
+            }
+            Declaration::Import(_) => {}
+        }
+    }
+
+    fn check_block(&mut self, body: &[Parsed<Statement>]) {
+        for statement in body.iter().flatten() {
+            self.check_statement(&statement.value);
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Let(let_) => {
+                if let Some(Ok(annotation)) = let_.annotation.as_deref() {
+                    self.check_type(&annotation.value.type_);
+                }
+                self.check_expression(&let_.value);
+            }
+            Statement::Assignment(assignment) => {
+                self.check_expression(&assignment.target);
+                self.check_expression(&assignment.value);
+            }
+            Statement::Expression(expression) => {
+                self.check_expression_value(expression)
+            }
+            Statement::Return(return_) => {
+                if let Some(value) = &return_.value {
+                    self.check_expression(value);
+                }
+            }
+            Statement::If(if_) => {
+                self.check_expression(&if_.condition);
+                self.check_block(&if_.body);
+                for elif in &if_.elifs {
+                    self.check_expression(&elif.condition);
+                    self.check_block(&elif.body);
+                }
+                if let Some(else_) = &if_.else_ {
+                    self.check_block(&else_.body);
+                }
+            }
+            Statement::Match(match_) => {
+                self.check_expression(&match_.scrutinee);
+                for arm in &match_.arms {
+                    self.check_block(&arm.body);
+                }
+            }
+            Statement::While(while_) => {
+                self.check_expression(&while_.condition);
+                self.check_block(&while_.body);
+            }
+            Statement::Loop(loop_) => self.check_block(&loop_.body),
+            Statement::For(for_) => {
+                self.check_expression(&for_.start);
+                self.check_expression(&for_.end);
+                self.check_block(&for_.body);
+            }
+            Statement::Break(_) | Statement::Continue(_) => {}
+        }
+    }
+
+    fn check_expression(&mut self, expression: &Parsed<Expression>) {
+        if let Ok(expression) = expression {
+            self.check_expression_value(&expression.value);
+        }
+    }
+
+    fn check_expression_value(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Integer(_)
+            | Expression::Float(_)
+            | Expression::String(_)
+            | Expression::Variable(_) => {}
+            Expression::Unary(unary) => self.check_expression(&unary.operand),
+            Expression::Binary(binary) => {
+                self.check_expression(&binary.left);
+                self.check_expression(&binary.right);
+            }
+            Expression::Parenthesized(parenthesized) => {
+                self.check_expression(&parenthesized.inner)
+            }
+            Expression::Call(call) => {
+                self.check_expression(&call.callee);
+                for argument in call.arguments.iter().flatten() {
+                    self.check_expression(&argument.value.value);
+                }
+            }
+            Expression::Construction(construction) => {
+                if let Some(qualifier) = &construction.qualifier {
+                    self.check_qualified(qualifier, &construction.record);
+                }
+                for field in construction.fields.iter().flatten() {
+                    self.check_expression(&field.value.value);
+                }
+            }
+            Expression::FieldAccess(access) => match &*access.object {
+                Ok(Spanned {
+                    value: Expression::Variable(module),
+                    ..
+                }) if self.is_module(module.symbol) => {
+                    self.check_item(module.symbol, &access.field)
+                }
+                object => self.check_expression(object),
+            },
+        }
+    }
+
+    fn check_type(&mut self, type_: &Parsed<TypeExpr>) {
+        let Ok(type_) = type_ else {
+            return;
+        };
+        match &type_.value {
+            TypeExpr::Named(_) => {}
+            TypeExpr::Qualified(qualified) => self
+                .check_qualified(&qualified.qualifier, &qualified.identifier),
+            TypeExpr::Generic(generic) => {
+                for argument in generic.arguments.iter().flatten() {
+                    self.check_type(&argument.value.type_);
+                }
+            }
+            TypeExpr::Tuple(tuple) => {
+                for element in tuple.elements.iter().flatten() {
+                    self.check_type(&element.value.type_);
+                }
+            }
+            TypeExpr::Function(function) => {
+                for parameter in function.parameters.iter().flatten() {
+                    self.check_type(&parameter.value.type_);
+                }
+                if let Some(return_type) = &function.return_type {
+                    self.check_type(&return_type.type_);
+                }
+            }
+        }
+    }
+
+    fn check_qualified(
+        &mut self,
+        qualifier: &Qualifier,
+        item: &Parsed<Identifier>,
+    ) {
+        let Ok(module) = &qualifier.module else {
+            return;
+        };
+        if !self.is_module(module.value.symbol) {
+            let error = CheckError::UnknownModule(module.value.symbol);
+            let error = error.into_spanned(module.span.clone());
+            self.pool.push(Report::Check(error));
+            return;
+        }
+        self.check_item(module.value.symbol, item);
+    }
+
+    /// Modules that failed to load are assumed to have every item, because
+    /// their errors are already reported.
+    fn check_item(&mut self, module: Symbol, item: &Parsed<Identifier>) {
+        let Ok(item) = item else {
+            return;
+        };
+        let Some(Some(index)) = self.module.import(module) else {
+            return;
+        };
+        let symbol = item.value.symbol;
+        let error = match self.items[index].visibility(symbol) {
+            Some(Visibility::Public) => return,
+            Some(Visibility::Private) => CheckError::PrivateItem {
+                module,
+                item: symbol,
+            },
+            None => CheckError::UnknownItem {
+                module,
+                item: symbol,
+            },
+        };
+        let error = error.into_spanned(item.span.clone());
+        self.pool.push(Report::Check(error));
+    }
+
+    fn is_module(&self, name: Symbol) -> bool {
+        self.module.import(name).is_some()
+    }
+}
@@ -0,0 +1,59 @@
+use emeraldc_parser::Declaration;
+use emeraldc_tree_checker::Report;
+
+use crate::{
+    ConstEvaluator, EnumTable, ItemTable, MatchChecker, Module,
+    QualifiedNameChecker,
+};
+
+/// Checks what needs declarations of the whole program, like variants of
+/// enums used in patterns, items of imported modules and values of
+/// constants.
+pub struct SemanticAnalyzer {}
+
+impl SemanticAnalyzer {
+    pub fn analyze(modules: &[Module]) -> impl Iterator<Item = Report> + use<> {
+        let items = modules.iter().map(ItemTable::collect).collect::<Vec<_>>();
+        let mut pool = Vec::new();
+        for module in modules {
+            let enums = Self::collect_enums(module, modules);
+            for declaration in module.declarations() {
+                QualifiedNameChecker::check(
+                    &declaration.value,
+                    module,
+                    &items,
+                    &mut pool,
+                );
+                if let Declaration::Function(function) = &declaration.value {
+                    MatchChecker::check(&function.body, &enums, &mut pool);
+                }
+            }
+        }
+        ConstEvaluator::evaluate(modules, &items, &mut pool);
+        pool.into_iter()
+    }
+
+    /// Enums declared in the module, then the public ones of the modules it
+    /// imports, in the order of the imports.
+    fn collect_enums(module: &Module, modules: &[Module]) -> EnumTable {
+        let imported = module
+            .imports
+            .iter()
+            .filter_map(|(_, index)| *index)
+            .map(|index| &modules[index]);
+        let mut table = EnumTable::new();
+        for declaration in module.declarations() {
+            if let Declaration::Enum(enum_) = &declaration.value {
+                table.declare(enum_, false);
+            }
+        }
+        for declaration in imported.flat_map(Module::declarations) {
+            if let Declaration::Enum(enum_) = &declaration.value
+                && enum_.public.is_some()
+            {
+                table.declare(enum_, true);
+            }
+        }
+        table
+    }
+}
@@ -0,0 +1,369 @@
+use emeraldc_interner::Symbol;
+
+use crate::{Constructor, DeconstructedPattern, EnumTable, Witness};
+
+/// Row of patterns tested against a row of values, one pattern per value.
+type Row = Vec<DeconstructedPattern>;
+
+/// Decides usefulness of patterns, as in Maranget's "Warnings for pattern
+/// matching".
+///
+/// A row is useful against the rows of a pattern matrix if some values are
+/// matched by the row, but by none of the matrix rows. A `case` is reachable
+/// if its pattern is useful against the cases above it, and a `match` is
+/// exhaustive if a wildcard is not useful against all of its cases.
+///
+/// There is no type checker yet, so the type of a column is taken from the
+/// variants in it. Columns without variants are assumed to have infinitely
+/// many values, so only a wildcard covers them.
+pub(crate) struct Usefulness<'t> {
+    table: &'t EnumTable,
+}
+
+impl<'t> Usefulness<'t> {
+    pub(crate) fn new(table: &'t EnumTable) -> Self {
+        Self { table }
+    }
+
+    pub(crate) fn is_useful(
+        &self,
+        rows: &[Row],
+        row: &[DeconstructedPattern],
+    ) -> bool {
+        let Some((head, tail)) = row.split_first() else {
+            return rows.is_empty();
+        };
+        match head {
+            DeconstructedPattern::Constructor(constructor, fields) => {
+                let rows = self.specialize(rows, constructor, fields.len());
+                let row = [fields.as_slice(), tail].concat();
+                self.is_useful(&rows, &row)
+            }
+            DeconstructedPattern::Wildcard => {
+                match self.complete_signature(rows) {
+                    Some(constructors) => {
+                        constructors.iter().any(|(constructor, arity)| {
+                            let rows =
+                                self.specialize(rows, constructor, *arity);
+                            let row = [&wildcards(*arity), tail].concat();
+                            self.is_useful(&rows, &row)
+                        })
+                    }
+                    None => self.is_useful(&Self::default_rows(rows), tail),
+                }
+            }
+        }
+    }
+
+    /// Rows of `width` values that none of the rows match.
+    pub(crate) fn missing(
+        &self,
+        rows: &[Row],
+        width: usize,
+    ) -> Vec<Vec<Witness>> {
+        if width == 0 {
+            return if rows.is_empty() {
+                vec![vec![]]
+            } else {
+                vec![]
+            };
+        }
+        let Some(enum_) = self.column_enum(rows) else {
+            return self
+                .missing(&Self::default_rows(rows), width - 1)
+                .into_iter()
+                .map(|rest| prepend(Witness::Wildcard, rest))
+                .collect();
+        };
+        // every variant is tried, including those no row starts with, so
+        // that all missing values are listed, and not only the first ones
+        self.enum_constructors(enum_)
+            .into_iter()
+            .flat_map(|(constructor, arity)| {
+                let rows = self.specialize(rows, &constructor, arity);
+                let name = self.name(&constructor);
+                self.missing(&rows, arity + width - 1).into_iter().map(
+                    move |mut fields| {
+                        let rest = fields.split_off(arity);
+                        prepend(Witness::Variant(name, fields), rest)
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Rows that match the constructor, with its fields in place of the
+    /// first pattern.
+    fn specialize(
+        &self,
+        rows: &[Row],
+        constructor: &Constructor,
+        arity: usize,
+    ) -> Vec<Row> {
+        rows.iter()
+            .filter_map(|row| {
+                let (head, tail) = row.split_first()?;
+                match head {
+                    DeconstructedPattern::Constructor(other, fields) => (other
+                        == constructor)
+                        .then(|| [fields.as_slice(), tail].concat()),
+                    DeconstructedPattern::Wildcard => {
+                        Some([&wildcards(arity), tail].concat())
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Rows that start with a wildcard, without it.
+    fn default_rows(rows: &[Row]) -> Vec<Row> {
+        rows.iter()
+            .filter_map(|row| match row.split_first()? {
+                (DeconstructedPattern::Wildcard, tail) => Some(tail.to_vec()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Every variant of the first column's enum with its arity, if all of
+    /// them start some row.
+    fn complete_signature(
+        &self,
+        rows: &[Row],
+    ) -> Option<Vec<(Constructor, usize)>> {
+        let enum_ = self.column_enum(rows)?;
+        let heads = Self::head_constructors(rows);
+        let constructors = self.enum_constructors(enum_);
+        constructors
+            .iter()
+            .all(|(constructor, _)| heads.contains(&constructor))
+            .then_some(constructors)
+    }
+
+    fn head_constructors(rows: &[Row]) -> Vec<&Constructor> {
+        rows.iter()
+            .filter_map(|row| match row.first()? {
+                DeconstructedPattern::Constructor(constructor, _) => {
+                    Some(constructor)
+                }
+                DeconstructedPattern::Wildcard => None,
+            })
+            .collect()
+    }
+
+    /// Enum of the first variant in the first column.
+    fn column_enum(&self, rows: &[Row]) -> Option<Symbol> {
+        Self::head_constructors(rows)
+            .into_iter()
+            .find_map(|constructor| match constructor {
+                Constructor::Variant { enum_, .. } => Some(*enum_),
+                Constructor::Literal(_) => None,
+            })
+    }
+
+    fn enum_constructors(&self, enum_: Symbol) -> Vec<(Constructor, usize)> {
+        self.table
+            .variants(enum_)
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| {
+                (Constructor::Variant { enum_, index }, variant.arity)
+            })
+            .collect()
+    }
+
+    fn name(&self, constructor: &Constructor) -> Symbol {
+        match constructor {
+            Constructor::Variant { enum_, index } => {
+                self.table.variant(*enum_, *index).name
+            }
+            Constructor::Literal(_) => {
+                unreachable!("literals are never listed")
+            }
+        }
+    }
+}
+
+fn wildcards(arity: usize) -> Row {
+    vec![DeconstructedPattern::Wildcard; arity]
+}
+
+fn prepend(witness: Witness, rest: Vec<Witness>) -> Vec<Witness> {
+    let mut row = vec![witness];
+    row.extend(rest);
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use emeraldc_lexer::Lexer;
+    use emeraldc_parser::{Declaration, Parser, Statement};
+    use emeraldc_span::SourceMap;
+    use emeraldc_tokenizer::Tokenizer;
+
+    use super::*;
+
+    /// Enums of the program and the patterns of the `match` that starts its
+    /// function, one row per `case`.
+    fn matrix(source: &str) -> (EnumTable, Vec<Row>) {
+        let mut source_map = SourceMap::new();
+        let file = source_map.add_file("test.ed", source);
+        let file = source_map.file(file);
+        let tokens = Lexer::lex(file, Tokenizer::tokenize(file.source()));
+        let declarations = Parser::parse(tokens)
+            .map(|declaration| declaration.unwrap().unwrap().value)
+            .collect::<Vec<_>>();
+        let mut table = EnumTable::new();
+        for declaration in &declarations {
+            if let Declaration::Enum(enum_) = declaration {
+                table.declare(enum_, false);
+            }
+        }
+        let match_ = declarations
+            .iter()
+            .find_map(|declaration| match declaration {
+                Declaration::Function(function) => {
+                    match &function.body[0].as_ref().unwrap().value {
+                        Statement::Match(match_) => Some(match_),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .unwrap();
+        let mut pool = Vec::new();
+        let rows = match_
+            .arms
+            .iter()
+            .map(|arm| {
+                vec![
+                    DeconstructedPattern::lower(
+                        &arm.pattern,
+                        &table,
+                        &mut pool,
+                    )
+                    .unwrap(),
+                ]
+            })
+            .collect();
+        assert!(pool.is_empty());
+        (table, rows)
+    }
+
+    fn missing(table: &EnumTable, rows: &[Row]) -> Vec<String> {
+        Usefulness::new(table)
+            .missing(rows, 1)
+            .into_iter()
+            .map(|witnesses| witnesses[0].to_string())
+            .collect()
+    }
+
+    const OPTION: &str = "
+enum Option
+    None
+    Some(Int)
+end
+";
+
+    #[test]
+    fn nested_variants() {
+        let source = format!(
+            "{OPTION}
+function f(x: Int)
+    match x
+    case Some(None)
+        return
+    case None
+        return
+    case Some(Some(y))
+        return
+    case Some(Some(None))
+        return
+    end
+end
+"
+        );
+        let (table, rows) = matrix(&source);
+        let usefulness = Usefulness::new(&table);
+        assert_eq!(missing(&table, &rows[..2]), ["Some(Some(_))"]);
+        assert!(usefulness.is_useful(&rows[..2], &rows[2]));
+        assert!(!usefulness.is_useful(&rows[..3], &rows[3]));
+        assert!(missing(&table, &rows[..3]).is_empty());
+    }
+
+    #[test]
+    fn literal_columns() {
+        let (table, rows) = matrix(
+            "
+function f(x: Int)
+    match x
+    case 1
+        return
+    case 0x1
+        return
+    case 2
+        return
+    case -1
+        return
+    case -0x1
+        return
+    case _
+        return
+    end
+end
+",
+        );
+        let usefulness = Usefulness::new(&table);
+        assert!(!usefulness.is_useful(&rows[..1], &rows[1]));
+        assert!(usefulness.is_useful(&rows[..2], &rows[2]));
+        assert!(usefulness.is_useful(&rows[..3], &rows[3]));
+        assert!(!usefulness.is_useful(&rows[..4], &rows[4]));
+        // literals never cover every value
+        assert_eq!(missing(&table, &rows[..5]), ["_"]);
+        assert!(missing(&table, &rows).is_empty());
+    }
+
+    #[test]
+    fn wildcard_after_complete_signature() {
+        let source = format!(
+            "{OPTION}
+function f(x: Int)
+    match x
+    case None
+        return
+    case Some(_)
+        return
+    case _
+        return
+    end
+end
+"
+        );
+        let (table, rows) = matrix(&source);
+        let usefulness = Usefulness::new(&table);
+        assert!(usefulness.is_useful(&rows[..1], &rows[2]));
+        assert!(!usefulness.is_useful(&rows[..2], &rows[2]));
+    }
+
+    #[test]
+    fn every_missing_variant_is_listed() {
+        let (table, rows) = matrix(
+            "
+enum Shape
+    Point
+    Circle(Int)
+    Rect(Int, Int)
+    Empty
+end
+
+function f(x: Int)
+    match x
+    case Circle(_)
+        return
+    end
+end
+",
+        );
+        assert_eq!(missing(&table, &rows), ["Point", "Rect(_, _)", "Empty"]);
+    }
+}
@@ -1,3 +1,6 @@
+mod source_map;
+pub use source_map::*;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,7 +16,7 @@ impl Span {
     }
 
     pub fn join(self, right: Self) -> Self {
-        assert!(self.end < right.start);
+        assert!(self.end <= right.start);
         Self::new(self.start, right.end)
     }
 }
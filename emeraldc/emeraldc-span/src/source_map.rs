@@ -0,0 +1,149 @@
+use crate::Span;
+
+/// Every file of the compilation session.
+///
+/// Files are laid out one after another in a single offset space, so a [`Span`] alone is
+/// enough to find its file.
+#[derive(Debug, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(
+        &mut self,
+        name: impl Into<String>,
+        source: impl Into<String>,
+    ) -> FileId {
+        let id = FileId(self.files.len() as u32);
+        // one-byte gap, so that the end of a file is not the start of the next one
+        let start = self.files.last().map_or(0, |file| file.end() + 1);
+        self.files
+            .push(SourceFile::new(id, name.into(), source.into(), start));
+        id
+    }
+
+    pub fn file(&self, id: FileId) -> &SourceFile {
+        &self.files[id.0 as usize]
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &SourceFile> {
+        self.files.iter()
+    }
+
+    /// File that contains the given offset.
+    pub fn lookup_file(&self, offset: usize) -> &SourceFile {
+        let index = self.files.partition_point(|file| file.start <= offset);
+        &self.files[index - 1]
+    }
+
+    pub fn location(&self, offset: usize) -> Location<'_> {
+        let file = self.lookup_file(offset);
+        let (line, column) = file.line_column(offset);
+        Location {
+            file_name: &file.name,
+            line,
+            column,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(u32);
+
+#[derive(Debug)]
+pub struct SourceFile {
+    id: FileId,
+    name: String,
+    source: String,
+    /// Offset of the first byte in the session-wide offset space.
+    start: usize,
+    /// Offsets of the first byte of every line, relative to the file start.
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(id: FileId, name: String, source: String, start: usize) -> Self {
+        let newlines = source.match_indices('\n').map(|(i, _)| i + 1);
+        let line_starts = std::iter::once(0).chain(newlines).collect();
+        Self {
+            id,
+            name,
+            source,
+            start,
+            line_starts,
+        }
+    }
+
+    pub fn id(&self) -> FileId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.start + self.source.len()
+    }
+
+    /// Text between two session-wide offsets.
+    pub fn slice(&self, start: usize, end: usize) -> &str {
+        &self.source[start - self.start..end - self.start]
+    }
+
+    pub fn text(&self, span: &Span) -> &str {
+        self.slice(span.start, span.end)
+    }
+
+    /// One-based line and column. Columns are counted in characters.
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let line = self.line_index(offset);
+        let line_start = self.start + self.line_starts[line];
+        let column = self.slice(line_start, offset).chars().count();
+        (line + 1, column + 1)
+    }
+
+    /// Text of the line that contains the offset, without the line break.
+    pub fn line_text(&self, offset: usize) -> &str {
+        let line = self.line_index(offset);
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.source.len(), |next| next - 1);
+        self.source[start..end].trim_end_matches('\r')
+    }
+
+    /// Zero-based index of the line that contains the offset.
+    fn line_index(&self, offset: usize) -> usize {
+        let relative = offset - self.start;
+        self.line_starts.partition_point(|start| *start <= relative) - 1
+    }
+}
+
+/// Human-readable position, printed as `file:line:column`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location<'m> {
+    pub file_name: &'m str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for Location<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file_name, self.line, self.column)
+    }
+}
@@ -1,6 +1,8 @@
+use unicode_xid::UnicodeXID;
+
 /// Група символа.
 pub enum ChGroup {
-    /// Символы, содержашиеся в названиях.
+    /// Символы, с которых могут начинаться названия: `_` и XID_Start из UAX #31.
     Alphabetic,
     /// Символы чисел.
     Numeric,
@@ -9,6 +11,8 @@ pub enum ChGroup {
         newline: bool,
     },
     Comment,
+    /// Кавычка, с которой начинается строка.
+    Quote,
     /// Другие символы, которые могут быть пунктуацией.
     MaybePunctuation,
 }
@@ -19,9 +23,19 @@ impl From<char> for ChGroup {
             'a'..='z' | 'A'..='Z' | '_' => Self::Alphabetic,
             '0'..='9' => Self::Numeric,
             ' ' | '\t' => Self::Invisible { newline: false },
-            '\n' => Self::Invisible { newline: true },
+            // `\r` из `\r\n` считается частью перевода строки
+            '\n' | '\r' => Self::Invisible { newline: true },
             '#' => Self::Comment,
+            '"' => Self::Quote,
+            ch if ch.is_xid_start() => Self::Alphabetic,
             _ => Self::MaybePunctuation,
         }
     }
 }
+
+impl ChGroup {
+    /// Может ли символ продолжать название, то есть является ли он XID_Continue.
+    pub fn is_identifier_continue(ch: char) -> bool {
+        ch.is_xid_continue()
+    }
+}
@@ -40,6 +40,13 @@ impl<'s> SourceBuffer<'s> {
         iter.next()
     }
 
+    /// Возвращает символ после текущего, не передвигая курсор.
+    pub fn peek_second(&self) -> Option<char> {
+        let mut iter = self.iter.clone();
+        iter.next();
+        iter.next()
+    }
+
     /// Обозначает начало нового токена.
     pub fn mark_token_start(&mut self) {
         let remaining_str = self.iter.as_str();
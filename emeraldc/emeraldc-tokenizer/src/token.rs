@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Токен.
 ///
 /// Содержит свой тип и длину.
@@ -25,15 +27,104 @@ impl Token {
 #[derive(Debug, Clone, Copy)]
 pub enum TokenKind {
     IdentifierOrKeyword,
-    Integer,
+    /// Целое число, возможно, с префиксом системы счисления и суффиксом.
+    Integer {
+        base: Base,
+        /// После префикса не было ни одной цифры, например, `0x`.
+        empty_digits: bool,
+        /// Смещение суффикса от начала токена. Равно длине токена, если суффикса нет.
+        suffix_start: usize,
+    },
+    /// Десятичное число с дробной частью и/или экспонентой.
+    Float {
+        /// После `e` не было ни одной цифры, например, `1e`.
+        empty_exponent: bool,
+        /// Смещение суффикса от начала токена. Равно длине токена, если суффикса нет.
+        suffix_start: usize,
+    },
+    /// Строка в кавычках. Экранированные последовательности проверяются лексером.
+    String {
+        terminated: bool,
+    },
     OpenRound,
     CloseRound,
+    OpenSquare,
+    CloseSquare,
     Equal,
+    DoubleEqual,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
     Plus,
+    PlusEqual,
     Minus,
+    MinusEqual,
+    Arrow,
     Asterisk,
+    AsteriskEqual,
+    DoubleAsterisk,
     Slash,
+    SlashEqual,
+    Percent,
+    Tilde,
+    Dot,
+    DoubleDot,
+    DoubleDotEqual,
+    Comma,
+    Colon,
+    /// `@`, с которого начинается метка цикла.
+    At,
     Invisible,
     Comment,
+    /// Комментарий `##`, документирующий следующее объявление.
+    DocComment,
+    /// Вложенный комментарий `#[ ... ]#`.
+    BlockComment {
+        terminated: bool,
+    },
     Unknown(char),
 }
+
+/// Система счисления целого числа.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Base {
+    /// Префикс `0b`.
+    Binary,
+    /// Префикс `0o`.
+    Octal,
+    Decimal,
+    /// Префикс `0x`.
+    Hexadecimal,
+}
+
+impl Base {
+    /// Длина префикса, например, `0x`.
+    pub fn prefix_length(&self) -> usize {
+        match self {
+            Self::Decimal => 0,
+            _ => 2,
+        }
+    }
+
+    pub fn radix(&self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+}
+
+impl std::fmt::Display for Base {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Binary => write!(f, "binary"),
+            Self::Octal => write!(f, "octal"),
+            Self::Decimal => write!(f, "decimal"),
+            Self::Hexadecimal => write!(f, "hexadecimal"),
+        }
+    }
+}
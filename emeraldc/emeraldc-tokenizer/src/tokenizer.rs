@@ -1,8 +1,10 @@
-use crate::{ChGroup, SourceBuffer, Token, TokenKind};
+use crate::{Base, ChGroup, SourceBuffer, Token, TokenKind};
 
 /// Токенизатор.
 pub struct Tokenizer<'s> {
     source_buffer: SourceBuffer<'s>,
+    /// Ещё не было токенов, кроме BOM. Только здесь допустим `#!`.
+    at_file_start: bool,
 }
 
 impl<'s> Tokenizer<'s> {
@@ -16,7 +18,10 @@ impl<'s> Tokenizer<'s> {
 impl<'s> Tokenizer<'s> {
     fn new(source: &'s str) -> Self {
         let source_buffer = SourceBuffer::new(source);
-        Self { source_buffer }
+        Self {
+            source_buffer,
+            at_file_start: true,
+        }
     }
 
     /// Вернёт токен, если в итераторе остались символы.
@@ -28,32 +33,180 @@ impl<'s> Tokenizer<'s> {
     /// Извлекает один токен из входной строки.
     fn token(&mut self) -> Token {
         let ch = self.source_buffer.peek().unwrap(); // всегда имеет значение
+        if self.at_file_start {
+            if let Some(token) = self.file_start_token(ch) {
+                return token;
+            }
+            self.at_file_start = false;
+        }
         let group = ChGroup::from(ch);
         self.token_starts_with(group)
     }
 
+    /// Извлекает токены, допустимые только в начале файла: BOM и строку `#!`.
+    fn file_start_token(&mut self, ch: char) -> Option<Token> {
+        match ch {
+            '\u{feff}' => Some(self.byte_order_mark_token()),
+            '#' if self.source_buffer.peek_second() == Some('!') => {
+                self.at_file_start = false;
+                Some(self.comment())
+            }
+            _ => None,
+        }
+    }
+
+    /// BOM не несёт смысла, поэтому становится невидимым токеном.
+    fn byte_order_mark_token(&mut self) -> Token {
+        self.source_buffer.mark_token_start();
+        self.source_buffer.eat();
+        Token::new(TokenKind::Invisible, self.source_buffer.token_length())
+    }
+
     /// Извлекает токен из входной строки, основываясь на группе текущего символа.
     fn token_starts_with(&mut self, group: ChGroup) -> Token {
         match group {
             ChGroup::Alphabetic => self.identifier_or_keyword_token(),
-            ChGroup::Numeric => self.integer_token(),
+            ChGroup::Numeric => self.number_token(),
             ChGroup::Invisible { .. } => self.invisible_token(),
             ChGroup::Comment => self.comment(),
+            ChGroup::Quote => self.string_token(),
             ChGroup::MaybePunctuation => self.punctuation_or_unknown_token(),
         }
     }
 
     fn identifier_or_keyword_token(&mut self) -> Token {
-        self.long_token_with_tracked_length(
-            TokenKind::IdentifierOrKeyword,
-            |cg| matches!(cg, ChGroup::Alphabetic),
-        )
+        self.source_buffer.mark_token_start();
+        self.source_buffer.eat(); // `_` или XID_Start
+        self.eat_identifier_continue();
+        let length = self.source_buffer.token_length();
+        Token::new(TokenKind::IdentifierOrKeyword, length)
     }
 
-    fn integer_token(&mut self) -> Token {
-        self.long_token_with_tracked_length(TokenKind::Integer, |cg| {
-            matches!(cg, ChGroup::Numeric)
-        })
+    fn eat_identifier_continue(&mut self) {
+        while self
+            .source_buffer
+            .peek()
+            .is_some_and(ChGroup::is_identifier_continue)
+        {
+            self.source_buffer.eat();
+        }
+    }
+
+    fn number_token(&mut self) -> Token {
+        self.source_buffer.mark_token_start();
+        let kind = self.number_token_kind();
+        Token::new(kind, self.source_buffer.token_length())
+    }
+
+    fn number_token_kind(&mut self) -> TokenKind {
+        match self.maybe_base_prefix() {
+            Some(base) => self.prefixed_integer_kind(base),
+            None => self.decimal_number_kind(),
+        }
+    }
+
+    fn prefixed_integer_kind(&mut self, base: Base) -> TokenKind {
+        self.source_buffer.eat(); // 0
+        self.source_buffer.eat(); // b, o или x
+        let empty_digits = !self.eat_digits(base);
+        let suffix_start = self.eat_suffix();
+        TokenKind::Integer {
+            base,
+            empty_digits,
+            suffix_start,
+        }
+    }
+
+    /// Десятичное целое число или число с плавающей точкой.
+    fn decimal_number_kind(&mut self) -> TokenKind {
+        self.eat_digits(Base::Decimal);
+        let has_fraction = self.is_fraction_start();
+        if has_fraction {
+            self.source_buffer.eat(); // .
+            self.eat_digits(Base::Decimal);
+        }
+        let has_exponent = self.is_exponent_start();
+        let empty_exponent = has_exponent && !self.eat_exponent();
+        let suffix_start = self.eat_suffix();
+        if has_fraction || has_exponent {
+            TokenKind::Float {
+                empty_exponent,
+                suffix_start,
+            }
+        } else {
+            TokenKind::Integer {
+                base: Base::Decimal,
+                empty_digits: false,
+                suffix_start,
+            }
+        }
+    }
+
+    fn maybe_base_prefix(&self) -> Option<Base> {
+        if self.source_buffer.peek() != Some('0') {
+            return None;
+        }
+        match self.source_buffer.peek_second()? {
+            'b' => Some(Base::Binary),
+            'o' => Some(Base::Octal),
+            'x' => Some(Base::Hexadecimal),
+            _ => None,
+        }
+    }
+
+    /// Пропускает цифры и разделители `_`. Вернёт `true`, если была хотя бы одна цифра.
+    ///
+    /// Для двоичных и восьмеричных чисел пропускаются все десятичные цифры, чтобы лексер мог
+    /// сообщить о неверной цифре, а не разделить число на несколько токенов.
+    fn eat_digits(&mut self, base: Base) -> bool {
+        let is_digit = |c: char| match base {
+            Base::Hexadecimal => c.is_ascii_hexdigit(),
+            _ => c.is_ascii_digit(),
+        };
+        let mut has_digits = false;
+        while let Some(ch) = self.source_buffer.peek() {
+            if is_digit(ch) {
+                has_digits = true;
+            } else if ch != '_' {
+                break;
+            }
+            self.source_buffer.eat();
+        }
+        has_digits
+    }
+
+    /// Точка, за которой следует цифра. Так `1..2` остаётся диапазоном.
+    fn is_fraction_start(&self) -> bool {
+        self.source_buffer.peek() == Some('.')
+            && self
+                .source_buffer
+                .peek_second()
+                .is_some_and(|c| c.is_ascii_digit())
+    }
+
+    fn is_exponent_start(&self) -> bool {
+        matches!(self.source_buffer.peek(), Some('e' | 'E'))
+    }
+
+    /// Пропускает экспоненту со знаком. Вернёт `true`, если в ней была хотя бы одна цифра.
+    fn eat_exponent(&mut self) -> bool {
+        self.source_buffer.eat(); // e
+        if matches!(self.source_buffer.peek(), Some('+' | '-')) {
+            self.source_buffer.eat();
+        }
+        self.eat_digits(Base::Decimal)
+    }
+
+    /// Пропускает суффикс, например, `u8`. Возвращает его смещение от начала токена.
+    fn eat_suffix(&mut self) -> usize {
+        let suffix_start = self.source_buffer.token_length();
+        if matches!(
+            self.source_buffer.peek().map(ChGroup::from),
+            Some(ChGroup::Alphabetic)
+        ) {
+            self.eat_identifier_continue();
+        }
+        suffix_start
     }
 
     fn invisible_token(&mut self) -> Token {
@@ -63,10 +216,86 @@ impl<'s> Tokenizer<'s> {
     }
 
     fn comment(&mut self) -> Token {
-        self.long_token_with_tracked_length(TokenKind::Comment, |cg| {
+        match self.source_buffer.peek_second() {
+            Some('[') => self.block_comment(),
+            Some('#') => self.line_comment(TokenKind::DocComment),
+            _ => self.line_comment(TokenKind::Comment),
+        }
+    }
+
+    fn line_comment(&mut self, kind: TokenKind) -> Token {
+        self.long_token_with_tracked_length(kind, |cg| {
             !matches!(cg, ChGroup::Invisible { newline: true })
         })
     }
+
+    fn block_comment(&mut self) -> Token {
+        self.source_buffer.mark_token_start();
+        let terminated = self.eat_block_comment();
+        let kind = TokenKind::BlockComment { terminated };
+        Token::new(kind, self.source_buffer.token_length())
+    }
+
+    /// Пропускает комментарий вместе со вложенными.
+    ///
+    /// Вернёт `false`, если комментарий закончился вместе с файлом.
+    fn eat_block_comment(&mut self) -> bool {
+        let mut depth = 0;
+        loop {
+            let ch = self.source_buffer.peek();
+            let next = self.source_buffer.peek_second();
+            match (ch, next) {
+                (None, _) => return false,
+                (Some('#'), Some('[')) => depth += 1,
+                (Some(']'), Some('#')) => depth -= 1,
+                _ => {
+                    self.source_buffer.eat();
+                    continue;
+                }
+            }
+            self.source_buffer.eat();
+            self.source_buffer.eat();
+            if depth == 0 {
+                return true;
+            }
+        }
+    }
+
+    fn string_token(&mut self) -> Token {
+        self.source_buffer.mark_token_start();
+        self.source_buffer.eat(); // открывающая кавычка
+        let terminated = self.eat_string_content();
+        let kind = TokenKind::String { terminated };
+        Token::new(kind, self.source_buffer.token_length())
+    }
+
+    /// Пропускает содержимое строки вместе с закрывающей кавычкой.
+    ///
+    /// Вернёт `false`, если строка не закрыта до конца строки или файла.
+    /// Перевод строки в незакрытую строку не входит, чтобы разбор
+    /// продолжился со следующей строки.
+    fn eat_string_content(&mut self) -> bool {
+        while let Some(ch) = self.source_buffer.peek() {
+            if is_newline(ch) {
+                return false;
+            }
+            self.source_buffer.eat();
+            match ch {
+                '"' => return true,
+                // экранированный символ не может закрыть строку
+                '\\' if self
+                    .source_buffer
+                    .peek()
+                    .is_some_and(|next| !is_newline(next)) =>
+                {
+                    self.source_buffer.eat();
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Создаёт токен, включая в него длину.
     fn long_token_with_tracked_length(
         &mut self,
@@ -84,16 +313,59 @@ impl<'s> Tokenizer<'s> {
         Token::new(kind, self.source_buffer.token_length())
     }
 
+    /// Выбирает самый длинный подходящий оператор.
     fn punctuation_or_unknown_token_kind(&mut self) -> TokenKind {
         match self.source_buffer.eat() {
             '(' => TokenKind::OpenRound,
             ')' => TokenKind::CloseRound,
+            '[' => TokenKind::OpenSquare,
+            ']' => TokenKind::CloseSquare,
+            ',' => TokenKind::Comma,
+            ':' => TokenKind::Colon,
+            '@' => TokenKind::At,
+            '%' => TokenKind::Percent,
+            '~' => TokenKind::Tilde,
+            '=' if self.eat_if('=') => TokenKind::DoubleEqual,
             '=' => TokenKind::Equal,
+            '!' if self.eat_if('=') => TokenKind::NotEqual,
+            '<' if self.eat_if('=') => TokenKind::LessEqual,
+            '<' => TokenKind::Less,
+            '>' if self.eat_if('=') => TokenKind::GreaterEqual,
+            '>' => TokenKind::Greater,
+            '+' if self.eat_if('=') => TokenKind::PlusEqual,
             '+' => TokenKind::Plus,
+            '-' if self.eat_if('=') => TokenKind::MinusEqual,
+            '-' if self.eat_if('>') => TokenKind::Arrow,
             '-' => TokenKind::Minus,
+            '*' if self.eat_if('=') => TokenKind::AsteriskEqual,
+            '*' if self.eat_if('*') => TokenKind::DoubleAsterisk,
             '*' => TokenKind::Asterisk,
+            '/' if self.eat_if('=') => TokenKind::SlashEqual,
             '/' => TokenKind::Slash,
+            '.' if self.eat_if('.') => self.double_dot_kind(),
+            '.' => TokenKind::Dot,
             c => TokenKind::Unknown(c),
         }
     }
+
+    fn double_dot_kind(&mut self) -> TokenKind {
+        if self.eat_if('=') {
+            TokenKind::DoubleDotEqual
+        } else {
+            TokenKind::DoubleDot
+        }
+    }
+
+    /// Пропускает символ, если он совпадает с ожидаемым.
+    fn eat_if(&mut self, expected: char) -> bool {
+        let matches = self.source_buffer.peek() == Some(expected);
+        if matches {
+            self.source_buffer.eat();
+        }
+        matches
+    }
+}
+
+fn is_newline(ch: char) -> bool {
+    matches!(ChGroup::from(ch), ChGroup::Invisible { newline: true })
 }
@@ -0,0 +1,220 @@
+use emeraldc_interner::Symbol;
+
+/// Error found in a syntactically valid tree.
+#[derive(Debug, Clone)]
+pub enum CheckError {
+    /// Function declares a return type, but its body may end without
+    /// `return`.
+    MissingReturn(Symbol),
+    /// Function declares a return type, but `return` has no value.
+    MissingReturnValue(Symbol),
+    /// Function declares no return type, but `return` has a value.
+    UnexpectedReturnValue(Symbol),
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    /// No enclosing loop has this label.
+    UnknownLabel(Symbol),
+    /// Record declaration or construction repeats the field.
+    DuplicateField(Symbol),
+    /// Enum declares the variant more than once.
+    DuplicateVariant(Symbol),
+    /// `match` doesn't cover the values, shown as patterns.
+    NonExhaustiveMatch(Vec<String>),
+    /// Pattern names a variant that no enum declares.
+    UnknownVariant(Symbol),
+    /// Pattern destructures a different number of fields than the variant
+    /// has.
+    VariantArity {
+        variant: Symbol,
+        expected: usize,
+        found: usize,
+    },
+    /// Pattern names a variant that several visible enums declare.
+    AmbiguousVariant {
+        variant: Symbol,
+        enums: Vec<Symbol>,
+    },
+    /// No file under the source root has the module path.
+    MissingModule(String),
+    /// Module file exists, but can't be read.
+    UnreadableModule(String),
+    /// Modules import each other, shown from the imported module back to
+    /// it.
+    ImportCycle(Vec<String>),
+    /// Another import already names a module by this last segment.
+    DuplicateImport(Symbol),
+    /// Qualifier names a module that is not imported.
+    UnknownModule(Symbol),
+    /// Imported module declares no item with this name.
+    UnknownItem {
+        module: Symbol,
+        item: Symbol,
+    },
+    /// Item of an imported module is not `public`.
+    PrivateItem {
+        module: Symbol,
+        item: Symbol,
+    },
+    /// Constant value refers to a name that is not a constant.
+    UnknownConstant(Symbol),
+    /// Constant value depends on the constant itself.
+    ConstantCycle(Symbol),
+    /// Constant value calls a function or builds a record.
+    NonConstantExpression,
+    /// Operator can't be applied to the constant operands.
+    InvalidConstantOperation,
+    ConstantOverflow,
+    DivisionByZero,
+    /// Constant value doesn't have its declared type or doesn't fit into it.
+    ConstantTypeMismatch(Symbol),
+}
+
+impl std::fmt::Display for CheckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MissingReturn(function) => {
+                write!(
+                    f,
+                    "function \x1b[3m'{function}'\x1b[m declares a return type, but may reach its end without \x1b[3m'return'\x1b[m"
+                )
+            }
+            Self::MissingReturnValue(function) => {
+                write!(
+                    f,
+                    "function \x1b[3m'{function}'\x1b[m declares a return type, but \x1b[3m'return'\x1b[m has no value"
+                )
+            }
+            Self::UnexpectedReturnValue(function) => {
+                write!(
+                    f,
+                    "function \x1b[3m'{function}'\x1b[m declares no return type, but \x1b[3m'return'\x1b[m has a value"
+                )
+            }
+            Self::BreakOutsideLoop => {
+                write!(f, "\x1b[3m'break'\x1b[m outside of a loop")
+            }
+            Self::ContinueOutsideLoop => {
+                write!(f, "\x1b[3m'continue'\x1b[m outside of a loop")
+            }
+            Self::UnknownLabel(label) => {
+                write!(
+                    f,
+                    "no enclosing loop is labeled \x1b[3m'@{label}'\x1b[m"
+                )
+            }
+            Self::DuplicateField(field) => {
+                write!(
+                    f,
+                    "field \x1b[3m'{field}'\x1b[m is given more than once"
+                )
+            }
+            Self::DuplicateVariant(variant) => {
+                write!(
+                    f,
+                    "variant \x1b[3m'{variant}'\x1b[m is declared more than once"
+                )
+            }
+            Self::NonExhaustiveMatch(missing) => {
+                write!(f, "match is not exhaustive, ")?;
+                write_missing_cases(f, missing)
+            }
+            Self::UnknownVariant(variant) => {
+                write!(f, "no enum declares variant \x1b[3m'{variant}'\x1b[m")
+            }
+            Self::VariantArity {
+                variant,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "variant \x1b[3m'{variant}'\x1b[m has {expected} field(s), but the pattern has {found}"
+                )
+            }
+            Self::AmbiguousVariant { variant, enums } => {
+                write!(
+                    f,
+                    "variant \x1b[3m'{variant}'\x1b[m is declared by several enums: "
+                )?;
+                for (index, enum_) in enums.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "\x1b[3m'{enum_}'\x1b[m")?;
+                }
+                Ok(())
+            }
+            Self::MissingModule(module) => {
+                write!(f, "no file for module \x1b[3m'{module}'\x1b[m")
+            }
+            Self::UnreadableModule(message) => write!(f, "{message}"),
+            Self::ImportCycle(modules) => {
+                let cycle = modules.join(" -> ");
+                write!(f, "import cycle: \x1b[3m{cycle}\x1b[m")
+            }
+            Self::DuplicateImport(module) => {
+                write!(
+                    f,
+                    "a module is already imported as \x1b[3m'{module}'\x1b[m"
+                )
+            }
+            Self::UnknownModule(module) => {
+                write!(f, "module \x1b[3m'{module}'\x1b[m is not imported")
+            }
+            Self::UnknownItem { module, item } => {
+                write!(
+                    f,
+                    "module \x1b[3m'{module}'\x1b[m has no item \x1b[3m'{item}'\x1b[m"
+                )
+            }
+            Self::PrivateItem { module, item } => {
+                write!(
+                    f,
+                    "item \x1b[3m'{item}'\x1b[m of module \x1b[3m'{module}'\x1b[m is private"
+                )
+            }
+            Self::UnknownConstant(name) => {
+                write!(f, "no constant is named \x1b[3m'{name}'\x1b[m")
+            }
+            Self::ConstantCycle(name) => {
+                write!(f, "constant \x1b[3m'{name}'\x1b[m depends on itself")
+            }
+            Self::NonConstantExpression => {
+                write!(f, "expression can't be evaluated at compile time")
+            }
+            Self::InvalidConstantOperation => {
+                write!(f, "operator can't be applied to these constant values")
+            }
+            Self::ConstantOverflow => {
+                write!(f, "constant arithmetic overflows")
+            }
+            Self::DivisionByZero => write!(f, "constant is divided by zero"),
+            Self::ConstantTypeMismatch(type_) => {
+                write!(
+                    f,
+                    "constant value doesn't fit into \x1b[3m'{type_}'\x1b[m"
+                )
+            }
+        }
+    }
+}
+
+/// Lists the first few missing cases and counts the rest.
+fn write_missing_cases(
+    f: &mut std::fmt::Formatter,
+    missing: &[String],
+) -> std::fmt::Result {
+    const SHOWN: usize = 3;
+    let noun = if missing.len() == 1 { "case" } else { "cases" };
+    write!(f, "missing {noun} ")?;
+    for (index, case) in missing.iter().take(SHOWN).enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "\x1b[3m'{case}'\x1b[m")?;
+    }
+    if missing.len() > SHOWN {
+        write!(f, " and {} more", missing.len() - SHOWN)?;
+    }
+    Ok(())
+}
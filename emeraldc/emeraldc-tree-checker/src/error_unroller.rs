@@ -2,10 +2,21 @@ use std::str;
 
 use emeraldc_lexer::WideToken;
 use emeraldc_parser::{
-    Binary, BinaryOperator, Declaration, Expression, FatalParserError,
-    Function, Identifier, Let, NodeError, Parenthesized, Parsed, Statement,
+    Argument, ArgumentName, Assignment, AssignmentOperator, Binary,
+    BinaryOperator, Break, Call, Const, Construction, Continue, Declaration,
+    Elif, Else, Enum, Expression, FatalParserError, Field, FieldAccess, For,
+    Function, FunctionType, Generic, Identifier, If, Import, Label, Let, Loop,
+    Match, MatchArm, NodeError, Parameter, Parenthesized, Parsed, PathSegment,
+    Pattern, PatternElement, Qualified, Qualifier, Record, Return, ReturnType,
+    Statement, Tuple, TypeAnnotation, TypeElement, TypeExpr, Unary,
+    UnaryOperator, Variant, VariantPattern, While,
+};
+use emeraldc_span::{IntoSpanned, SourceFile, SourceMap, Span, Spanned};
+
+use crate::{
+    CheckError, FieldChecker, LoopChecker, ReturnChecker, SideEffectChecker,
+    Warning,
 };
-use emeraldc_span::{IntoSpanned, Span, Spanned};
 
 pub struct ErrorUnroller {}
 
@@ -37,6 +48,37 @@ where
     }
 }
 
+impl<T> Unroll for Option<T>
+where
+    T: Unroll,
+{
+    fn unroll(self, pool: &mut Vec<Report>) {
+        if let Some(node) = self {
+            node.unroll(pool);
+        }
+    }
+}
+
+impl<T> Unroll for Box<T>
+where
+    T: Unroll,
+{
+    fn unroll(self, pool: &mut Vec<Report>) {
+        (*self).unroll(pool);
+    }
+}
+
+impl<T> Unroll for Vec<T>
+where
+    T: Unroll,
+{
+    fn unroll(self, pool: &mut Vec<Report>) {
+        for node in self {
+            node.unroll(pool);
+        }
+    }
+}
+
 impl<T> Unroll for Parsed<T>
 where
     Spanned<T>: Unroll,
@@ -55,6 +97,18 @@ impl Unroll for Spanned<Declaration> {
             Declaration::Function(function) => {
                 function.into_spanned(self.span).unroll(pool)
             }
+            Declaration::Record(record) => {
+                record.into_spanned(self.span).unroll(pool)
+            }
+            Declaration::Enum(enum_) => {
+                enum_.into_spanned(self.span).unroll(pool)
+            }
+            Declaration::Const(const_) => {
+                const_.into_spanned(self.span).unroll(pool)
+            }
+            Declaration::Import(import) => {
+                import.into_spanned(self.span).unroll(pool)
+            }
         }
     }
 }
@@ -62,27 +116,240 @@ impl Unroll for Spanned<Declaration> {
 impl Unroll for Spanned<Function> {
     fn unroll(self, pool: &mut Vec<Report>) {
         let this = self.value;
+        if this.return_type.is_some()
+            && !ReturnChecker::always_returns(&this.body)
+            && let Ok(identifier) = &this.identifier
+        {
+            let span = match &this._end {
+                Ok(end) => end.span.clone(),
+                Err(_) => self.span.clone(),
+            };
+            let error = CheckError::MissingReturn(identifier.value.symbol);
+            pool.push(Report::Check(error.into_spanned(span)));
+        }
+        if let Ok(identifier) = &this.identifier {
+            ReturnChecker::check_values(
+                identifier.value.symbol,
+                this.return_type.is_some(),
+                &this.body,
+                pool,
+            );
+        }
+        LoopChecker::check(&this.body, pool);
+        this.public.unroll(pool);
         this._introducer.unroll(pool);
         this.identifier.unroll(pool);
         this._open_round.unroll(pool);
+        this.parameters.unroll(pool);
         this._close_round.unroll(pool);
+        this.return_type.unroll(pool);
         this.body.unroll(pool);
         this._end.unroll(pool);
     }
 }
 
-impl Unroll for Vec<Parsed<Statement>> {
+impl Unroll for Spanned<Record> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        FieldChecker::check_declaration(&this.fields, pool);
+        this.public.unroll(pool);
+        this._introducer.unroll(pool);
+        this.identifier.unroll(pool);
+        this.fields.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Field> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.identifier.unroll(pool);
+        this.annotation.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Enum> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        FieldChecker::check_enum(&this.variants, pool);
+        this.public.unroll(pool);
+        this._introducer.unroll(pool);
+        this.identifier.unroll(pool);
+        this.variants.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Variant> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.identifier.unroll(pool);
+        this.payload.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Const> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.public.unroll(pool);
+        this._introducer.unroll(pool);
+        this.identifier.unroll(pool);
+        this.annotation.unroll(pool);
+        this._equal.unroll(pool);
+        this.value.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Import> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.public.unroll(pool);
+        this._introducer.unroll(pool);
+        this.segments.unroll(pool);
+    }
+}
+
+impl Unroll for PathSegment {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self._dot.unroll(pool);
+        self.identifier.unroll(pool);
+    }
+}
+
+impl Unroll for Qualifier {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self.module.unroll(pool);
+        self._dot.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Parameter> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.identifier.unroll(pool);
+        this.annotation.unroll(pool);
+        this._comma.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<TypeAnnotation> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._colon.unroll(pool);
+        this.type_.unroll(pool);
+    }
+}
+
+impl Unroll for ReturnType {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self._arrow.unroll(pool);
+        self.type_.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<TypeExpr> {
     fn unroll(self, pool: &mut Vec<Report>) {
-        for statement in self {
-            statement.unroll(pool);
+        match self.value {
+            TypeExpr::Named(identifier) => {
+                identifier.into_spanned(self.span).unroll(pool)
+            }
+            TypeExpr::Qualified(qualified) => {
+                qualified.into_spanned(self.span).unroll(pool)
+            }
+            TypeExpr::Generic(generic) => {
+                generic.into_spanned(self.span).unroll(pool)
+            }
+            TypeExpr::Tuple(tuple) => {
+                tuple.into_spanned(self.span).unroll(pool)
+            }
+            TypeExpr::Function(function) => {
+                function.into_spanned(self.span).unroll(pool)
+            }
         }
     }
 }
 
+impl Unroll for Spanned<Qualified> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.qualifier.unroll(pool);
+        this.identifier.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Generic> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.identifier.unroll(pool);
+        this._open_square.unroll(pool);
+        this.arguments.unroll(pool);
+        this._close_square.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Tuple> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._open_round.unroll(pool);
+        this.elements.unroll(pool);
+        this._close_round.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<FunctionType> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this._open_round.unroll(pool);
+        this.parameters.unroll(pool);
+        this._close_round.unroll(pool);
+        this.return_type.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<TypeElement> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.type_.unroll(pool);
+        this._comma.unroll(pool);
+    }
+}
+
 impl Unroll for Spanned<Statement> {
     fn unroll(self, pool: &mut Vec<Report>) {
         match self.value {
             Statement::Let(let_) => let_.into_spanned(self.span).unroll(pool),
+            Statement::Assignment(assignment) => {
+                assignment.into_spanned(self.span).unroll(pool)
+            }
+            Statement::Expression(expression) => {
+                if !SideEffectChecker::has_side_effects(&expression) {
+                    let warning =
+                        Warning::UnusedValue.into_spanned(self.span.clone());
+                    pool.push(Report::Warning(warning));
+                }
+                expression.into_spanned(self.span).unroll(pool)
+            }
+            Statement::Return(return_) => {
+                return_.into_spanned(self.span).unroll(pool)
+            }
+            Statement::If(if_) => if_.into_spanned(self.span).unroll(pool),
+            Statement::Match(match_) => {
+                match_.into_spanned(self.span).unroll(pool)
+            }
+            Statement::While(while_) => {
+                while_.into_spanned(self.span).unroll(pool)
+            }
+            Statement::Loop(loop_) => {
+                loop_.into_spanned(self.span).unroll(pool)
+            }
+            Statement::For(for_) => for_.into_spanned(self.span).unroll(pool),
+            Statement::Break(break_) => {
+                break_.into_spanned(self.span).unroll(pool)
+            }
+            Statement::Continue(continue_) => {
+                continue_.into_spanned(self.span).unroll(pool)
+            }
         }
     }
 }
@@ -92,28 +359,210 @@ impl Unroll for Spanned<Let> {
         let this = self.value;
         this._introducer.unroll(pool);
         this.identifier.unroll(pool);
+        this.annotation.unroll(pool);
         this._equal.unroll(pool);
         this.value.unroll(pool);
     }
 }
 
+impl Unroll for Spanned<Assignment> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.target.unroll(pool);
+        this.operator.unroll(pool);
+        this.value.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<AssignmentOperator> {
+    fn unroll(self, _pool: &mut Vec<Report>) {}
+}
+
+impl Unroll for Spanned<Return> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this.value.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<If> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this.condition.unroll(pool);
+        this.body.unroll(pool);
+        this.elifs.unroll(pool);
+        this.else_.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Elif {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self._introducer.unroll(pool);
+        self.condition.unroll(pool);
+        self.body.unroll(pool);
+    }
+}
+
+impl Unroll for Else {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self._introducer.unroll(pool);
+        self.body.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Match> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this.scrutinee.unroll(pool);
+        this.arms.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for MatchArm {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self._introducer.unroll(pool);
+        self.pattern.unroll(pool);
+        self.body.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Pattern> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        match self.value {
+            Pattern::Wildcard => {}
+            Pattern::Literal(literal) => {
+                literal.into_spanned(self.span).unroll(pool)
+            }
+            Pattern::Binding(identifier) => {
+                identifier.into_spanned(self.span).unroll(pool)
+            }
+            Pattern::Variant(variant) => {
+                variant.into_spanned(self.span).unroll(pool)
+            }
+        }
+    }
+}
+
+impl Unroll for Spanned<VariantPattern> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.identifier.unroll(pool);
+        this._open_round.unroll(pool);
+        this.fields.unroll(pool);
+        this._close_round.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<PatternElement> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.pattern.unroll(pool);
+        this._comma.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Label> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._at.unroll(pool);
+        this.identifier.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<While> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.label.unroll(pool);
+        this._introducer.unroll(pool);
+        this.condition.unroll(pool);
+        this.body.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Loop> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.label.unroll(pool);
+        this._introducer.unroll(pool);
+        this.body.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<For> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.label.unroll(pool);
+        this._introducer.unroll(pool);
+        this.variable.unroll(pool);
+        this._in.unroll(pool);
+        this.start.unroll(pool);
+        this._double_dot.unroll(pool);
+        this.end.unroll(pool);
+        this.body.unroll(pool);
+        this._end.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Break> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this.label.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Continue> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this._introducer.unroll(pool);
+        this.label.unroll(pool);
+    }
+}
+
 impl Unroll for Spanned<Expression> {
     fn unroll(self, pool: &mut Vec<Report>) {
         match self.value {
-            Expression::Integer => {}
+            Expression::Integer(_)
+            | Expression::Float(_)
+            | Expression::String(_) => {}
             Expression::Variable(identifier) => {
                 identifier.into_spanned(self.span).unroll(pool)
             }
+            Expression::Unary(unary) => {
+                unary.into_spanned(self.span).unroll(pool)
+            }
             Expression::Binary(binary) => {
                 binary.into_spanned(self.span).unroll(pool)
             }
             Expression::Parenthesized(parenthesized) => {
                 parenthesized.into_spanned(self.span).unroll(pool)
             }
+            Expression::Call(call) => call.into_spanned(self.span).unroll(pool),
+            Expression::Construction(construction) => {
+                construction.into_spanned(self.span).unroll(pool)
+            }
+            Expression::FieldAccess(field_access) => {
+                field_access.into_spanned(self.span).unroll(pool)
+            }
         }
     }
 }
 
+impl Unroll for Spanned<Unary> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.operator.unroll(pool);
+        this.operand.unroll(pool);
+    }
+}
+
 impl Unroll for Spanned<Binary> {
     fn unroll(self, pool: &mut Vec<Report>) {
         let this = self.value;
@@ -132,6 +581,57 @@ impl Unroll for Spanned<Parenthesized> {
     }
 }
 
+impl Unroll for Spanned<Call> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.callee.unroll(pool);
+        this._open_round.unroll(pool);
+        this.arguments.unroll(pool);
+        this._close_round.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Construction> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        FieldChecker::check_construction(&this.fields, pool);
+        this.qualifier.unroll(pool);
+        this.record.unroll(pool);
+        this._open_round.unroll(pool);
+        this.fields.unroll(pool);
+        this._close_round.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<FieldAccess> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.object.unroll(pool);
+        this._dot.unroll(pool);
+        this.field.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<Argument> {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        let this = self.value;
+        this.name.unroll(pool);
+        this.value.unroll(pool);
+        this._comma.unroll(pool);
+    }
+}
+
+impl Unroll for Spanned<UnaryOperator> {
+    fn unroll(self, _pool: &mut Vec<Report>) {}
+}
+
+impl Unroll for ArgumentName {
+    fn unroll(self, pool: &mut Vec<Report>) {
+        self.identifier.unroll(pool);
+        self._equal.unroll(pool);
+    }
+}
+
 impl Unroll for Spanned<BinaryOperator> {
     fn unroll(self, _pool: &mut Vec<Report>) {}
 }
@@ -160,86 +660,96 @@ impl Unroll for FatalParserError {
 pub enum Report {
     Node(Spanned<NodeError>),
     Fatal(FatalParserError),
+    Check(Spanned<CheckError>),
+    Warning(Spanned<Warning>),
 }
 
 impl std::fmt::Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Self::Node(spanned_error) => {
-                write!(
-                    f,
-                    "\x1b[31merror\x1b[m[{}] {}",
-                    spanned_error.span, spanned_error.value
-                )
-            }
-            Self::Fatal(error) => {
-                write!(f, "\x1b[31merror\x1b[m[?] {error}")
+        match self.span() {
+            Some(span) => {
+                write!(f, "{}[{span}] {}", self.severity(), self.message())
             }
+            None => write!(f, "{}[?] {}", self.severity(), self.message()),
         }
     }
 }
 
 impl Report {
-    pub fn with_preview<'s>(self, source: &'s str) -> FullReport<'s> {
-        FullReport::new(self, source)
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::Node(spanned_error) => Some(&spanned_error.span),
+            Self::Fatal(_) => None,
+            Self::Check(spanned_error) => Some(&spanned_error.span),
+            Self::Warning(spanned_warning) => Some(&spanned_warning.span),
+        }
+    }
+
+    /// Colored severity label.
+    fn severity(&self) -> &'static str {
+        match self {
+            Self::Node(_) | Self::Fatal(_) | Self::Check(_) => {
+                "\x1b[31merror\x1b[m"
+            }
+            Self::Warning(_) => "\x1b[33mwarning\x1b[m",
+        }
+    }
+
+    fn message(&self) -> &dyn std::fmt::Display {
+        match self {
+            Self::Node(spanned_error) => &spanned_error.value,
+            Self::Fatal(error) => error,
+            Self::Check(spanned_error) => &spanned_error.value,
+            Self::Warning(spanned_warning) => &spanned_warning.value,
+        }
+    }
+
+    pub fn with_preview(self, source_map: &SourceMap) -> FullReport<'_> {
+        FullReport::new(self, source_map)
     }
 }
 
+/// Report with its `file:line:column` location and a preview of the line.
 #[derive(Debug)]
-pub struct FullReport<'s> {
+pub struct FullReport<'m> {
     pub report: Report,
-    pub source: &'s str,
+    pub source_map: &'m SourceMap,
 }
 
-impl<'s> FullReport<'s> {
-    pub fn new(report: Report, source: &'s str) -> Self {
-        Self { report, source }
+impl<'m> FullReport<'m> {
+    pub fn new(report: Report, source_map: &'m SourceMap) -> Self {
+        Self { report, source_map }
     }
 }
 
 impl std::fmt::Display for FullReport<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.report)?;
-        match &self.report {
-            Report::Node(spanned_error) => {
-                writeln!(f)?;
-                let span = &spanned_error.span;
-                let (start, end) = self.find_line_boundaries(span);
-                writeln!(f, "\x1b[31m|\x1b[m")?;
-                writeln!(
-                    f,
-                    "\x1b[31m|\x1b[m {}",
-                    self.source[start..end].to_string()
-                )?;
-                let pointer_line = self.pointer_line(span, start);
-                write!(f, "\x1b[31m|\x1b[m {pointer_line}")
-            }
-            _ => Ok(()),
-        }
+        let Some(span) = self.report.span() else {
+            return write!(f, "{}", self.report);
+        };
+        let location = self.source_map.location(span.start);
+        let severity = self.report.severity();
+        writeln!(f, "{severity}[{location}] {}", self.report.message())?;
+        let file = self.source_map.lookup_file(span.start);
+        writeln!(f, "\x1b[31m|\x1b[m")?;
+        writeln!(f, "\x1b[31m|\x1b[m {}", file.line_text(span.start))?;
+        let pointer_line = self.pointer_line(file, span, location.column);
+        write!(f, "\x1b[31m|\x1b[m {pointer_line}")
     }
 }
 
 impl FullReport<'_> {
-    fn find_line_boundaries(&self, span: &Span) -> (usize, usize) {
-        let start = self.source[..span.start]
-            .rfind('\n')
-            .map(|i| i + 1)
-            .unwrap_or(0);
-        let end = self.source[span.end..]
-            .find('\n')
-            .map(|i| span.end + i)
-            .unwrap_or_else(|| self.source.len());
-        (start, end)
-    }
-
+    /// Underlines the span, up to the end of its first line.
     fn pointer_line(
         &self,
+        file: &SourceFile,
         span: &Span,
-        start: usize,
+        column: usize,
     ) -> impl std::fmt::Display {
-        let pointer_start = span.start - start;
-        let pointer_length = span.end - span.start;
-        " ".repeat(pointer_start)
+        let text = file.text(span);
+        let first_line = text.lines().next().unwrap_or(text);
+        let pointer_length = first_line.chars().count().max(1);
+        " ".repeat(column - 1)
             + "\x1b[33m"
             + &"^".repeat(pointer_length)
             + " here\x1b[m"
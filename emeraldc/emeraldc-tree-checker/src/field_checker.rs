@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{Argument, Field, Identifier, Parsed, Variant};
+use emeraldc_span::{IntoSpanned, Spanned};
+
+use crate::{CheckError, Report};
+
+/// Checks that every field of a record and every variant of an enum is named
+/// once.
+pub(crate) struct FieldChecker {}
+
+impl FieldChecker {
+    pub(crate) fn check_declaration(
+        fields: &[Parsed<Field>],
+        pool: &mut Vec<Report>,
+    ) {
+        let names = fields
+            .iter()
+            .flatten()
+            .filter_map(|field| field.value.identifier.as_ref().ok());
+        Self::check_duplicates(names, pool, CheckError::DuplicateField);
+    }
+
+    pub(crate) fn check_enum(
+        variants: &[Parsed<Variant>],
+        pool: &mut Vec<Report>,
+    ) {
+        let names = variants
+            .iter()
+            .flatten()
+            .filter_map(|variant| variant.value.identifier.as_ref().ok());
+        Self::check_duplicates(names, pool, CheckError::DuplicateVariant);
+    }
+
+    pub(crate) fn check_construction(
+        fields: &[Parsed<Argument>],
+        pool: &mut Vec<Report>,
+    ) {
+        let names = fields
+            .iter()
+            .flatten()
+            .filter_map(|field| field.value.name.as_ref())
+            .filter_map(|name| name.identifier.as_ref().ok());
+        Self::check_duplicates(names, pool, CheckError::DuplicateField);
+    }
+
+    /// Reports every repetition of an already seen name.
+    fn check_duplicates<'a>(
+        names: impl Iterator<Item = &'a Spanned<Identifier>>,
+        pool: &mut Vec<Report>,
+        duplicate: fn(Symbol) -> CheckError,
+    ) {
+        let mut seen = HashSet::new();
+        for name in names {
+            if !seen.insert(name.value.symbol) {
+                let error = duplicate(name.value.symbol)
+                    .into_spanned(name.span.clone());
+                pool.push(Report::Check(error));
+            }
+        }
+    }
+}
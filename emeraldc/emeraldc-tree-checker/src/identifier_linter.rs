@@ -0,0 +1,70 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::WideToken;
+use emeraldc_span::{IntoSpanned, Spanned};
+use unicode_security::{MixedScript, skeleton};
+
+use crate::{Report, Warning};
+
+/// Looks for identifiers that could be mistaken for each other, as described in UTS #39.
+pub struct IdentifierLinter {
+    seen: HashSet<Symbol>,
+    /// First identifier seen with the given skeleton.
+    skeletons: HashMap<String, Symbol>,
+    pool: Vec<Report>,
+}
+
+impl IdentifierLinter {
+    pub fn lint(
+        tokens: &[Spanned<WideToken>],
+    ) -> impl Iterator<Item = Report> + use<> {
+        let mut linter = Self::new();
+        for token in tokens {
+            if let WideToken::Identifier(symbol) = token.value {
+                linter.lint_identifier(symbol.into_spanned(token.span.clone()));
+            }
+        }
+        linter.pool.into_iter()
+    }
+
+    fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+            skeletons: HashMap::new(),
+            pool: Vec::new(),
+        }
+    }
+
+    /// Lints only the first occurrence of every identifier.
+    fn lint_identifier(&mut self, identifier: Spanned<Symbol>) {
+        if !self.seen.insert(identifier.value) {
+            return;
+        }
+        let text = identifier.value.as_str();
+        if !text.is_single_script() {
+            self.warn(
+                Warning::MixedScriptIdentifier(identifier.value),
+                &identifier,
+            );
+        }
+        match self.skeletons.entry(skeleton(text).collect()) {
+            Entry::Occupied(entry) => {
+                let warning = Warning::ConfusableIdentifier {
+                    identifier: identifier.value,
+                    seen: *entry.get(),
+                };
+                self.warn(warning, &identifier);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(identifier.value);
+            }
+        }
+    }
+
+    fn warn(&mut self, warning: Warning, identifier: &Spanned<Symbol>) {
+        let warning = warning.into_spanned(identifier.span.clone());
+        self.pool.push(Report::Warning(warning));
+    }
+}
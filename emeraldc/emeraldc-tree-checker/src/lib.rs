@@ -1,2 +1,16 @@
+mod check_error;
 mod error_unroller;
+mod field_checker;
+mod identifier_linter;
+mod loop_checker;
+mod return_checker;
+mod side_effect_checker;
+mod warning;
+pub use check_error::*;
 pub use error_unroller::*;
+use field_checker::*;
+pub use identifier_linter::*;
+use loop_checker::*;
+use return_checker::*;
+use side_effect_checker::*;
+pub use warning::*;
@@ -0,0 +1,113 @@
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{Label, Parsed, Statement};
+use emeraldc_span::{IntoSpanned, Span};
+
+use crate::{CheckError, Report};
+
+/// Checks that `break` and `continue` jump out of an enclosing loop.
+pub(crate) struct LoopChecker<'r> {
+    /// Labels of the enclosing loops, innermost last.
+    loops: Vec<Option<Symbol>>,
+    pool: &'r mut Vec<Report>,
+}
+
+impl<'r> LoopChecker<'r> {
+    pub(crate) fn check(body: &[Parsed<Statement>], pool: &'r mut Vec<Report>) {
+        let mut this = Self {
+            loops: Vec::new(),
+            pool,
+        };
+        this.check_block(body);
+    }
+
+    fn check_block(&mut self, body: &[Parsed<Statement>]) {
+        for statement in body.iter().flatten() {
+            self.check_statement(&statement.value, &statement.span);
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Statement, span: &Span) {
+        match statement {
+            Statement::Let(_)
+            | Statement::Assignment(_)
+            | Statement::Expression(_)
+            | Statement::Return(_) => {}
+            Statement::If(if_) => {
+                self.check_block(&if_.body);
+                for elif in &if_.elifs {
+                    self.check_block(&elif.body);
+                }
+                if let Some(else_) = &if_.else_ {
+                    self.check_block(&else_.body);
+                }
+            }
+            Statement::Match(match_) => {
+                for arm in &match_.arms {
+                    self.check_block(&arm.body);
+                }
+            }
+            Statement::While(while_) => {
+                self.check_loop(&while_.label, &while_.body)
+            }
+            Statement::Loop(loop_) => {
+                self.check_loop(&loop_.label, &loop_.body)
+            }
+            Statement::For(for_) => self.check_loop(&for_.label, &for_.body),
+            Statement::Break(break_) => self.check_jump(
+                &break_.label,
+                span,
+                CheckError::BreakOutsideLoop,
+            ),
+            Statement::Continue(continue_) => self.check_jump(
+                &continue_.label,
+                span,
+                CheckError::ContinueOutsideLoop,
+            ),
+        }
+    }
+
+    fn check_loop(
+        &mut self,
+        label: &Option<Parsed<Label>>,
+        body: &[Parsed<Statement>],
+    ) {
+        self.loops.push(label_symbol(label));
+        self.check_block(body);
+        self.loops.pop();
+    }
+
+    fn check_jump(
+        &mut self,
+        label: &Option<Parsed<Label>>,
+        span: &Span,
+        outside_loop: CheckError,
+    ) {
+        if self.loops.is_empty() {
+            let error = outside_loop.into_spanned(span.clone());
+            self.pool.push(Report::Check(error));
+            return;
+        }
+        let Some(symbol) = label_symbol(label) else {
+            return;
+        };
+        if !self.loops.contains(&Some(symbol)) {
+            let span = match label {
+                Some(Ok(label)) => label.span.clone(),
+                _ => span.clone(),
+            };
+            let error = CheckError::UnknownLabel(symbol).into_spanned(span);
+            self.pool.push(Report::Check(error));
+        }
+    }
+}
+
+/// Name of the label, if it is present and parsed.
+pub(crate) fn label_symbol(label: &Option<Parsed<Label>>) -> Option<Symbol> {
+    match label {
+        Some(Ok(label)) => match &label.value.identifier {
+            Ok(identifier) => Some(identifier.value.symbol),
+            Err(_) => None,
+        },
+        _ => None,
+    }
+}
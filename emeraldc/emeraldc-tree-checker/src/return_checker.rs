@@ -0,0 +1,172 @@
+use emeraldc_interner::Symbol;
+use emeraldc_parser::{If, Loop, Match, Parsed, Statement};
+use emeraldc_span::IntoSpanned;
+
+use crate::{CheckError, Report, label_symbol};
+
+/// Checks that control flow can't fall off the end of a function body.
+pub(crate) struct ReturnChecker {}
+
+impl ReturnChecker {
+    /// Whether every path through the statements ends with `return`.
+    ///
+    /// A `return` without a value counts too, because it is reported by
+    /// [`Self::check_values`].
+    ///
+    /// Statements that failed to parse are assumed to return, because
+    /// their errors are already reported.
+    pub(crate) fn always_returns(body: &[Parsed<Statement>]) -> bool {
+        body.iter().any(Self::statement_returns)
+    }
+
+    fn statement_returns(statement: &Parsed<Statement>) -> bool {
+        match statement {
+            Ok(statement) => match &statement.value {
+                Statement::Let(_)
+                | Statement::Assignment(_)
+                | Statement::Expression(_) => false,
+                Statement::Return(_) => true,
+                Statement::If(if_) => Self::if_returns(if_),
+                Statement::Match(match_) => Self::match_returns(match_),
+                // the body may not run even once
+                Statement::While(_) | Statement::For(_) => false,
+                Statement::Loop(loop_) => Self::loop_returns(loop_),
+                Statement::Break(_) | Statement::Continue(_) => false,
+            },
+            Err(_) => true,
+        }
+    }
+
+    /// Reports every `return` without a value in the body of a function that
+    /// declares a return type, and every `return` with a value in the body of
+    /// a function that doesn't.
+    ///
+    /// `typed` tells whether the function declares a return type.
+    pub(crate) fn check_values(
+        function: Symbol,
+        typed: bool,
+        body: &[Parsed<Statement>],
+        pool: &mut Vec<Report>,
+    ) {
+        for statement in body.iter().flatten() {
+            match &statement.value {
+                Statement::Return(return_) => match &return_.value {
+                    None if typed => {
+                        let error = CheckError::MissingReturnValue(function);
+                        let error = error.into_spanned(statement.span.clone());
+                        pool.push(Report::Check(error));
+                    }
+                    Some(Ok(value)) if !typed => {
+                        let error = CheckError::UnexpectedReturnValue(function);
+                        let error = error.into_spanned(value.span.clone());
+                        pool.push(Report::Check(error));
+                    }
+                    _ => {}
+                },
+                Statement::If(if_) => {
+                    Self::check_values(function, typed, &if_.body, pool);
+                    for elif in &if_.elifs {
+                        Self::check_values(function, typed, &elif.body, pool);
+                    }
+                    if let Some(else_) = &if_.else_ {
+                        Self::check_values(function, typed, &else_.body, pool);
+                    }
+                }
+                Statement::Match(match_) => {
+                    for arm in &match_.arms {
+                        Self::check_values(function, typed, &arm.body, pool);
+                    }
+                }
+                Statement::While(while_) => {
+                    Self::check_values(function, typed, &while_.body, pool)
+                }
+                Statement::Loop(loop_) => {
+                    Self::check_values(function, typed, &loop_.body, pool)
+                }
+                Statement::For(for_) => {
+                    Self::check_values(function, typed, &for_.body, pool)
+                }
+                Statement::Let(_)
+                | Statement::Assignment(_)
+                | Statement::Expression(_)
+                | Statement::Break(_)
+                | Statement::Continue(_) => {}
+            }
+        }
+    }
+
+    /// `if` returns only when it has `else` and all of its branches return.
+    fn if_returns(if_: &If) -> bool {
+        let Some(else_) = &if_.else_ else {
+            return false;
+        };
+        Self::always_returns(&if_.body)
+            && if_
+                .elifs
+                .iter()
+                .all(|elif| Self::always_returns(&elif.body))
+            && Self::always_returns(&else_.body)
+    }
+
+    /// `match` returns when all of its arms return.
+    ///
+    /// Whether the arms cover every value is checked separately.
+    fn match_returns(match_: &Match) -> bool {
+        !match_.arms.is_empty()
+            && match_
+                .arms
+                .iter()
+                .all(|arm| Self::always_returns(&arm.body))
+    }
+
+    /// `loop` never finishes unless a `break` leaves it.
+    fn loop_returns(loop_: &Loop) -> bool {
+        let label = label_symbol(&loop_.label);
+        !Self::breaks_out(&loop_.body, label, false)
+    }
+
+    /// Whether some `break` in the statements leaves the loop with the label.
+    ///
+    /// Unlabeled `break` leaves it only outside of nested loops.
+    fn breaks_out(
+        body: &[Parsed<Statement>],
+        label: Option<Symbol>,
+        nested: bool,
+    ) -> bool {
+        body.iter()
+            .flatten()
+            .any(|statement| match &statement.value {
+                Statement::Break(break_) => match label_symbol(&break_.label) {
+                    Some(symbol) => Some(symbol) == label,
+                    None => break_.label.is_none() && !nested,
+                },
+                Statement::If(if_) => {
+                    Self::breaks_out(&if_.body, label, nested)
+                        || if_.elifs.iter().any(|elif| {
+                            Self::breaks_out(&elif.body, label, nested)
+                        })
+                        || if_.else_.as_ref().is_some_and(|else_| {
+                            Self::breaks_out(&else_.body, label, nested)
+                        })
+                }
+                Statement::Match(match_) => match_
+                    .arms
+                    .iter()
+                    .any(|arm| Self::breaks_out(&arm.body, label, nested)),
+                Statement::While(while_) => {
+                    Self::breaks_out(&while_.body, label, true)
+                }
+                Statement::Loop(loop_) => {
+                    Self::breaks_out(&loop_.body, label, true)
+                }
+                Statement::For(for_) => {
+                    Self::breaks_out(&for_.body, label, true)
+                }
+                Statement::Let(_)
+                | Statement::Assignment(_)
+                | Statement::Expression(_)
+                | Statement::Return(_)
+                | Statement::Continue(_) => false,
+            })
+    }
+}
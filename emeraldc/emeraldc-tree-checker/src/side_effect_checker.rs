@@ -0,0 +1,49 @@
+use emeraldc_parser::{Expression, Parsed};
+
+/// Finds expressions that are evaluated only for their value.
+pub(crate) struct SideEffectChecker {}
+
+impl SideEffectChecker {
+    /// Whether evaluating the expression may do something besides producing
+    /// a value.
+    ///
+    /// Every call is assumed to have side effects, and so are expressions
+    /// that failed to parse, because their errors are already reported.
+    pub(crate) fn has_side_effects(expression: &Expression) -> bool {
+        match expression {
+            Expression::Integer(_)
+            | Expression::Float(_)
+            | Expression::String(_)
+            | Expression::Variable(_) => false,
+            Expression::Unary(unary) => {
+                Self::parsed_has_side_effects(&unary.operand)
+            }
+            Expression::Binary(binary) => {
+                Self::parsed_has_side_effects(&binary.left)
+                    || Self::parsed_has_side_effects(&binary.right)
+            }
+            Expression::Parenthesized(parenthesized) => {
+                Self::parsed_has_side_effects(&parenthesized.inner)
+            }
+            Expression::Call(_) => true,
+            Expression::Construction(construction) => {
+                construction.fields.iter().any(|field| match field {
+                    Ok(field) => {
+                        Self::parsed_has_side_effects(&field.value.value)
+                    }
+                    Err(_) => true,
+                })
+            }
+            Expression::FieldAccess(field_access) => {
+                Self::parsed_has_side_effects(&field_access.object)
+            }
+        }
+    }
+
+    fn parsed_has_side_effects(expression: &Parsed<Expression>) -> bool {
+        match expression {
+            Ok(expression) => Self::has_side_effects(&expression.value),
+            Err(_) => true,
+        }
+    }
+}
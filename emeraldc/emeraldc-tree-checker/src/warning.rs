@@ -0,0 +1,40 @@
+use emeraldc_interner::Symbol;
+
+/// Suspicious code that does not stop the compilation.
+#[derive(Debug, Clone)]
+pub enum Warning {
+    /// Identifier mixes scripts, for example, Latin and Cyrillic letters.
+    MixedScriptIdentifier(Symbol),
+    /// Identifier looks the same as another, already seen one.
+    ConfusableIdentifier { identifier: Symbol, seen: Symbol },
+    /// Value of an expression statement without side effects is never used.
+    UnusedValue,
+    /// `case` can't match anything, because the cases above it already
+    /// match all of its values.
+    UnreachableCase,
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::MixedScriptIdentifier(identifier) => {
+                write!(
+                    f,
+                    "identifier \x1b[3m'{identifier}'\x1b[m mixes several scripts"
+                )
+            }
+            Self::ConfusableIdentifier { identifier, seen } => {
+                write!(
+                    f,
+                    "identifier \x1b[3m'{identifier}'\x1b[m is confusable with \x1b[3m'{seen}'\x1b[m"
+                )
+            }
+            Self::UnusedValue => {
+                write!(f, "expression has no effect and its value is unused")
+            }
+            Self::UnreachableCase => {
+                write!(f, "case is unreachable, earlier cases already match it")
+            }
+        }
+    }
+}
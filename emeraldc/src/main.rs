@@ -1,24 +1,38 @@
-use emeraldc_lexer::Lexer;
-use emeraldc_parser::{Declaration, FatalParserError, Parsed, Parser};
-use emeraldc_tokenizer::Tokenizer;
-use emeraldc_tree_checker::{ErrorUnroller, Report};
+mod module_loader;
+mod module_resolver;
 
-fn parse_tree(
-    source: &str,
-) -> impl Iterator<Item = Result<Parsed<Declaration>, FatalParserError>> {
-    let thin_tokens = Tokenizer::tokenize(source);
-    let tokens = Lexer::lex(source, thin_tokens);
-    let parse_tree = Parser::parse(tokens);
-    parse_tree
-}
+use std::path::Path;
+
+use emeraldc_semantic::SemanticAnalyzer;
+use emeraldc_span::SourceMap;
+use emeraldc_tree_checker::ErrorUnroller;
+use module_loader::*;
+use module_resolver::*;
 
 fn main() {
     env_logger::init();
 
-    let source = std::fs::read_to_string("source.ed").unwrap();
-    let pt = parse_tree(&source);
-    for report in ErrorUnroller::unroll(pt) {
-        let report = report.with_preview(&source);
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "source.ed".to_string());
+    let mut source_map = SourceMap::new();
+    let program = match ModuleLoader::load(Path::new(&path), &mut source_map) {
+        Ok(program) => program,
+        Err(message) => {
+            eprintln!("\x1b[31merror\x1b[m {message}");
+            std::process::exit(1);
+        }
+    };
+    let semantic = SemanticAnalyzer::analyze(&program.modules);
+    let trees = program.modules.into_iter().flat_map(|module| module.tree);
+    let reports = program
+        .reports
+        .into_iter()
+        .chain(ErrorUnroller::unroll(trees))
+        .chain(semantic)
+        .chain(program.lints);
+    for report in reports {
+        let report = report.with_preview(&source_map);
         eprintln!("{report}");
     }
 }
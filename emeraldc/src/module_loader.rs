@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use emeraldc_interner::Symbol;
+use emeraldc_lexer::{Lexer, WideToken};
+use emeraldc_parser::{Declaration, FatalParserError, Parsed, Parser};
+use emeraldc_semantic::Module;
+use emeraldc_span::{IntoSpanned, SourceFile, SourceMap, Span, Spanned};
+use emeraldc_tokenizer::Tokenizer;
+use emeraldc_tree_checker::{CheckError, IdentifierLinter, Report};
+
+use crate::ModuleResolver;
+
+/// Modules of the program, starting with the main one.
+pub struct Program {
+    pub modules: Vec<Module>,
+    /// Imports that failed to load.
+    pub reports: Vec<Report>,
+    pub lints: Vec<Report>,
+}
+
+/// Loads the main file and every module it imports, directly or not.
+pub struct ModuleLoader<'m> {
+    resolver: ModuleResolver,
+    source_map: &'m mut SourceMap,
+    modules: Vec<Module>,
+    /// Index of every loaded module by its file.
+    loaded: HashMap<PathBuf, usize>,
+    /// Modules whose imports are being loaded, each one imported by the one
+    /// before it.
+    stack: Vec<usize>,
+    reports: Vec<Report>,
+    lints: Vec<Report>,
+}
+
+impl<'m> ModuleLoader<'m> {
+    /// Modules are looked up in the directory of the main file.
+    pub fn load(
+        main: &Path,
+        source_map: &'m mut SourceMap,
+    ) -> Result<Program, String> {
+        let source = read_source(main)?;
+        let root = main.parent().unwrap_or(Path::new("")).to_path_buf();
+        let mut this = Self {
+            resolver: ModuleResolver::new(root),
+            source_map,
+            modules: Vec::new(),
+            loaded: HashMap::new(),
+            stack: Vec::new(),
+            reports: Vec::new(),
+            lints: Vec::new(),
+        };
+        let name = this.resolver.module_name(main);
+        this.load_module(name, main, source);
+        Ok(Program {
+            modules: this.modules,
+            reports: this.reports,
+            lints: this.lints,
+        })
+    }
+
+    fn load_module(
+        &mut self,
+        name: String,
+        path: &Path,
+        source: String,
+    ) -> usize {
+        let file = self.source_map.add_file(path.display().to_string(), source);
+        let tokens = lex(self.source_map.file(file));
+        self.lints.extend(IdentifierLinter::lint(&tokens));
+        let tree = Parser::parse(tokens.into_iter()).collect::<Vec<_>>();
+        let imports = Self::imports(&tree);
+        let index = self.modules.len();
+        self.loaded.insert(canonical(path), index);
+        self.modules.push(Module {
+            name,
+            tree,
+            imports: Vec::new(),
+        });
+        self.stack.push(index);
+        for (path, span) in imports {
+            let last = *path.last().unwrap();
+            if self.modules[index].import(last).is_some() {
+                self.report(CheckError::DuplicateImport(last), span);
+                continue;
+            }
+            let imported = self.load_import(&path, span);
+            self.modules[index].imports.push((last, imported));
+        }
+        self.stack.pop();
+        index
+    }
+
+    /// Paths of the imports that were parsed, with their spans.
+    fn imports(
+        tree: &[Result<Parsed<Declaration>, FatalParserError>],
+    ) -> Vec<(Vec<Symbol>, Span)> {
+        tree.iter()
+            .flatten()
+            .flatten()
+            .filter_map(|declaration| {
+                let Declaration::Import(import) = &declaration.value else {
+                    return None;
+                };
+                let path = import
+                    .segments
+                    .iter()
+                    .map(|segment| {
+                        let identifier = segment.identifier.as_ref().ok()?;
+                        Some(identifier.value.symbol)
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some((path, declaration.span.clone()))
+            })
+            .collect()
+    }
+
+    /// Reports the errors at the import, returning the module unless it
+    /// failed to load.
+    ///
+    /// Modules of a cycle are still returned, so that their items can be
+    /// looked up.
+    fn load_import(&mut self, path: &[Symbol], span: Span) -> Option<usize> {
+        let name = path
+            .iter()
+            .map(|segment| segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        let Some(file) = self.resolver.resolve(path) else {
+            self.report(CheckError::MissingModule(name), span);
+            return None;
+        };
+        if let Some(&index) = self.loaded.get(&canonical(&file)) {
+            if let Some(start) = self.stack.iter().position(|&i| i == index) {
+                let cycle = self.stack[start..]
+                    .iter()
+                    .chain([&index])
+                    .map(|&i| self.modules[i].name.clone())
+                    .collect();
+                self.report(CheckError::ImportCycle(cycle), span);
+            }
+            return Some(index);
+        }
+        match read_source(&file) {
+            Ok(source) => Some(self.load_module(name, &file, source)),
+            Err(message) => {
+                self.report(CheckError::UnreadableModule(message), span);
+                None
+            }
+        }
+    }
+
+    fn report(&mut self, error: CheckError, span: Span) {
+        self.reports.push(Report::Check(error.into_spanned(span)));
+    }
+}
+
+fn lex(file: &SourceFile) -> Vec<Spanned<WideToken>> {
+    let thin_tokens = Tokenizer::tokenize(file.source());
+    Lexer::lex(file, thin_tokens).collect()
+}
+
+/// Reads the whole file, describing the failure in a human-readable way.
+fn read_source(path: &Path) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|error| {
+        format!("could not read {}: {error}", path.display())
+    })?;
+    let path = path.display();
+    String::from_utf8(bytes).map_err(|error| {
+        let offset = error.utf8_error().valid_up_to();
+        format!("{path} is not valid UTF-8: invalid byte at offset {offset}")
+    })
+}
+
+/// Same files have the same canonical path, however they are reached.
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
@@ -0,0 +1,35 @@
+use std::path::{Path, PathBuf};
+
+use emeraldc_interner::Symbol;
+
+/// Maps module paths to `.ed` files under the source root.
+pub struct ModuleResolver {
+    root: PathBuf,
+}
+
+impl ModuleResolver {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// File of `a.b.c` is `a/b/c.ed` under the root, if it exists.
+    pub fn resolve(&self, path: &[Symbol]) -> Option<PathBuf> {
+        let mut file = self.root.clone();
+        for segment in path {
+            file.push(segment.as_str());
+        }
+        file.set_extension("ed");
+        file.is_file().then_some(file)
+    }
+
+    /// Dotted path of the module in the file.
+    pub fn module_name(&self, file: &Path) -> String {
+        let relative = file.strip_prefix(&self.root).unwrap_or(file);
+        relative
+            .with_extension("")
+            .iter()
+            .map(|segment| segment.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}